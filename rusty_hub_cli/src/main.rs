@@ -0,0 +1,510 @@
+use anyhow::{anyhow, bail, Context};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use table::Table;
+use unity_hub_lib::{
+    batch,
+    editor_process::RunningEditor,
+    hub::Hub,
+    license::{self, LicenseStatus},
+    paths,
+    project_scan::CancelToken,
+    search_path::SearchPath,
+    test_runner::{self, TestPlatform},
+    unity_editor::UnityEditor,
+    unity_project::UnityProject,
+};
+
+mod table;
+
+/// Same location as the egui client, both share the list of projects and editors.
+const CONFIG_APP: &str = "rusty_hub_egui";
+const CONFIG_NAME: &str = "config";
+
+#[derive(Parser)]
+#[command(version, about = "Command line interface of Rusty Unity Hub")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List known projects.
+    Projects {
+        #[arg(long)]
+        json: bool,
+        /// Include hidden projects.
+        #[arg(long)]
+        all: bool,
+    },
+    /// List installed editors.
+    Editors {
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show details of a project.
+    Project {
+        /// Path or title of the project.
+        project: String,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Open a project in its editor.
+    Open {
+        /// Path or title of the project.
+        project: String,
+        /// Editor version to use instead of the one matching the project.
+        #[arg(long)]
+        editor: Option<String>,
+        /// Wait for the editor to exit, fails when it crashed.
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Print the editor a project would be opened with.
+    WhichEditor {
+        /// Path or title of the project.
+        project: String,
+    },
+    /// Run tests of a project in batch mode, fails when any test fails.
+    Test {
+        /// Path or title of the project.
+        project: String,
+        #[arg(long, value_enum, default_value_t = Platform::EditMode)]
+        platform: Platform,
+        /// Only run tests which full name matches, passed as `-testFilter`.
+        #[arg(long)]
+        filter: Option<String>,
+        /// Print the editor log while the tests run.
+        #[arg(long)]
+        verbose: bool,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Look for projects in a directory and add them to the list.
+    Scan { dir: PathBuf },
+    /// Rescan editors, project roots and projects.
+    Rescan {
+        /// Ignore cached information.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print the Unity license of this machine, fails when it expired.
+    License {
+        /// License file to read instead of the default one.
+        #[arg(long)]
+        file: Option<PathBuf>,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Manage editor search paths.
+    #[command(subcommand)]
+    SearchPath(SearchPathCommand),
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Platform {
+    EditMode,
+    PlayMode,
+}
+
+impl From<Platform> for TestPlatform {
+    fn from(platform: Platform) -> Self {
+        match platform {
+            Platform::EditMode => TestPlatform::EditMode,
+            Platform::PlayMode => TestPlatform::PlayMode,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum SearchPathCommand {
+    List,
+    Add { path: String },
+    Remove { path: String },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let mut hub = load_hub()?;
+
+    match cli.command {
+        Command::Projects { json, all } => {
+            let projects = hub.projects.iter().filter(|p| all || !p.hidden);
+            print_projects(&hub, projects, json)?;
+        }
+        Command::Editors { json } => print_editors(&hub.config.editors_configurations, json)?,
+        Command::Project { project, json } => {
+            let project = find_project(&hub, &project)?;
+            print_project(&hub, project, json)?;
+        }
+        Command::Open {
+            project,
+            editor,
+            wait,
+        } => {
+            let project = find_project(&hub, &project)?;
+            let editor = match editor {
+                Some(version) => hub
+                    .config
+                    .editors_configurations
+                    .iter()
+                    .find(|editor| editor.version == version)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("Editor {} is not installed", version))?,
+                None => editor_for_project(&hub, project)?,
+            };
+            let running =
+                Hub::run_project(&editor, project).context("Failed to start the editor")?;
+            if wait {
+                wait_for_editor(running)?;
+            }
+        }
+        Command::WhichEditor { project } => {
+            let project = find_project(&hub, &project)?;
+            let editor = editor_for_project(&hub, project)?;
+            println!("{} {}", editor.version, editor.exe_path);
+        }
+        Command::Test {
+            project,
+            platform,
+            filter,
+            verbose,
+            json,
+        } => {
+            let project = find_project(&hub, &project)?;
+            let editor = editor_for_project(&hub, project)?;
+            run_tests(&editor, project, platform.into(), filter, verbose, json)?;
+        }
+        Command::Scan { dir } => {
+            let report = hub.search_for_projects_at_path(&absolute(&dir)?);
+            store_hub(&hub)?;
+            println!(
+                "{} new project(s), {} duplicate(s), {} folder(s) skipped",
+                report.found, report.duplicates, report.skipped
+            );
+            for error in report.errors {
+                eprintln!("{}", error);
+            }
+        }
+        Command::Rescan { force } => {
+            if force {
+                hub.force_update_data();
+            } else {
+                hub.update_data();
+            }
+            store_hub(&hub)?;
+            println!(
+                "{} editor(s), {} project(s)",
+                hub.config.editors_configurations.len(),
+                hub.projects.len()
+            );
+        }
+        Command::License { file, json } => {
+            print_license(&file.unwrap_or_else(license::license_path), json)?
+        }
+        Command::SearchPath(command) => search_path(&mut hub, command)?,
+    }
+    Ok(())
+}
+
+fn load_hub() -> anyhow::Result<Hub> {
+    confy::load(CONFIG_APP, CONFIG_NAME).context("Failed to load the hub config")
+}
+
+fn store_hub(hub: &Hub) -> anyhow::Result<()> {
+    confy::store(CONFIG_APP, CONFIG_NAME, hub).context("Failed to store the hub config")
+}
+
+/// Resolves a path given on the command line, stored relative paths would depend on the
+/// directory of the next run.
+fn absolute(path: &Path) -> anyhow::Result<PathBuf> {
+    let resolved = path
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", path.display()))?;
+    // Keeps the usual `C:\` form instead of the verbatim `\\?\` one on Windows.
+    if resolved.to_string_lossy().starts_with(r"\\?\") {
+        return Ok(std::path::absolute(path)?);
+    }
+    Ok(resolved)
+}
+
+/// Finds a project by its path or, when no path matches, by its title.
+fn find_project<'a>(hub: &'a Hub, query: &str) -> anyhow::Result<&'a UnityProject> {
+    let canonical = Path::new(query).canonicalize().ok();
+    if let Some(project) = hub.projects.iter().find(|project| {
+        project.path == query
+            || canonical.is_some() && Path::new(&project.path).canonicalize().ok() == canonical
+    }) {
+        return Ok(project);
+    }
+
+    let matches: Vec<_> = hub
+        .projects
+        .iter()
+        .filter(|project| project.title.eq_ignore_ascii_case(query))
+        .collect();
+    match matches.as_slice() {
+        [] => bail!("No project matches {}", query),
+        [project] => Ok(project),
+        _ => bail!(
+            "{} projects are called {}, use the path instead:\n{}",
+            matches.len(),
+            query,
+            matches
+                .iter()
+                .map(|project| project.path.as_str())
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+    }
+}
+
+fn editor_for_project(hub: &Hub, project: &UnityProject) -> anyhow::Result<UnityEditor> {
+    hub.editor_for_project(project)
+        .ok_or_else(|| anyhow!("Editor {} is not installed", project.version))
+}
+
+fn print_projects<'a>(
+    hub: &Hub,
+    projects: impl Iterator<Item = &'a UnityProject>,
+    json: bool,
+) -> anyhow::Result<()> {
+    if json {
+        let projects: Vec<_> = projects.map(|p| project_json(hub, p)).collect();
+        println!("{}", serde_json::to_string_pretty(&projects)?);
+        return Ok(());
+    }
+    let mut table = Table::new(["TITLE", "VERSION", "BRANCH", "EDITOR", "PATH"]);
+    for project in projects {
+        let editor = if !project.is_valid {
+            "project missing"
+        } else if hub.editor_for_project(project).is_some() {
+            "installed"
+        } else {
+            "missing"
+        };
+        table.row([
+            project.title.clone(),
+            project.version.clone(),
+            project.branch.clone(),
+            editor.to_string(),
+            project.path.clone(),
+        ]);
+    }
+    table.print();
+    Ok(())
+}
+
+fn print_editors(editors: &[UnityEditor], json: bool) -> anyhow::Result<()> {
+    if json {
+        let editors: Vec<_> = editors.iter().map(editor_json).collect();
+        println!("{}", serde_json::to_string_pretty(&editors)?);
+        return Ok(());
+    }
+    let mut table = Table::new(["VERSION", "ARCH", "PLATFORMS", "PATH"]);
+    for editor in editors {
+        table.row([
+            editor.version.clone(),
+            editor.architecture.name().to_string(),
+            editor.platforms.len().to_string(),
+            editor.base_path.clone(),
+        ]);
+    }
+    table.print();
+    Ok(())
+}
+
+fn print_project(hub: &Hub, project: &UnityProject, json: bool) -> anyhow::Result<()> {
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&project_json(hub, project))?
+        );
+        return Ok(());
+    }
+    let editor = hub
+        .editor_for_project(project)
+        .map(|editor| editor.exe_path)
+        .unwrap_or_else(|| "not installed".to_string());
+    println!("Title:        {}", project.title);
+    println!("Path:         {}", project.path);
+    println!("Exists:       {}", project.is_valid);
+    println!("Version:      {}", project.version);
+    println!("Editor:       {}", editor);
+    println!("Branch:       {}", project.branch);
+    println!("Product name: {}", project.product_name);
+    println!("Git remote:   {}", project.git_remote);
+    println!("Hidden:       {}", project.hidden);
+    Ok(())
+}
+
+fn project_json(hub: &Hub, project: &UnityProject) -> serde_json::Value {
+    json!({
+        "title": project.title,
+        "path": project.path,
+        "exists": project.is_valid,
+        "version": project.version,
+        "editor": hub.editor_for_project(project).map(|editor| editor.exe_path),
+        "branch": project.branch,
+        "product_name": project.product_name,
+        "git_remote": project.git_remote,
+        "hidden": project.hidden,
+    })
+}
+
+fn editor_json(editor: &UnityEditor) -> serde_json::Value {
+    json!({
+        "version": editor.version,
+        "exe_path": editor.exe_path,
+        "base_path": editor.base_path,
+        "architecture": editor.architecture.name(),
+        "platforms": editor.platforms,
+        "installed_size": editor.installed_size,
+    })
+}
+
+fn run_tests(
+    editor: &UnityEditor,
+    project: &UnityProject,
+    platform: TestPlatform,
+    filter: Option<String>,
+    verbose: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let job = test_runner::test_job(editor, project, platform, filter.as_deref());
+    let results = PathBuf::from(test_runner::results_path(&job));
+    let _ = std::fs::remove_file(&results);
+    eprintln!("{}", job.command_line());
+    let outcome = batch::run(&job, &CancelToken::default(), |line| {
+        if verbose {
+            eprintln!("{}", line);
+        }
+    })?;
+    let run = test_runner::read_results(&results).with_context(|| {
+        format!(
+            "Editor exited with {:?} without test results, see {}",
+            outcome.exit_code,
+            outcome.log_file.display()
+        )
+    })?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&run)?);
+    } else {
+        for case in run.failures() {
+            println!("FAILED {}", case.full_name);
+            if let Some(message) = &case.message {
+                println!("  {}", message.replace('\n', "\n  "));
+            }
+            if let Some(stack_trace) = &case.stack_trace {
+                println!("  {}", stack_trace.replace('\n', "\n  "));
+            }
+        }
+        println!(
+            "{} test(s): {} passed, {} failed, {} skipped in {:.2}s",
+            run.total, run.passed, run.failed, run.skipped, run.duration
+        );
+    }
+    if !run.succeeded() {
+        bail!("{} test(s) failed", run.failed);
+    }
+    if run.total == 0 {
+        eprintln!("No tests were run");
+    }
+    Ok(())
+}
+
+fn wait_for_editor(running: RunningEditor) -> anyhow::Result<()> {
+    let exit = running.wait().context("Failed to wait for the editor")?;
+    if !exit.crashed() {
+        return Ok(());
+    }
+    eprintln!("Last lines of {}:", exit.log_file.display());
+    for line in &exit.log_tail {
+        eprintln!("  {}", line);
+    }
+    for report in &exit.crash_reports {
+        eprintln!("Crash report: {}", report.display());
+    }
+    bail!("Editor stopped with {}", exit.status());
+}
+
+fn print_license(path: &Path, json: bool) -> anyhow::Result<()> {
+    let license = license::read_license(path).with_context(|| path.display().to_string())?;
+    let status = license.status(std::time::SystemTime::now());
+    if json {
+        println!("{}", serde_json::to_string_pretty(&license)?);
+    } else {
+        let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        println!("File:         {}", path.display());
+        println!("Type:         {}", license.kind.name());
+        println!("Serial:       {}", license.serial_masked);
+        println!("Activated:    {}", optional(&license.activated));
+        println!("Updated:      {}", optional(&license.updated));
+        println!(
+            "Expires:      {}",
+            license.expires.as_deref().unwrap_or("never")
+        );
+        println!("Machine ID:   {}", optional(&license.machine_id));
+        for binding in &license.bindings {
+            println!("Binding {}:    {}", binding.key, binding.value);
+        }
+        println!("Entitlements: {}", license.entitlements.join(", "));
+        println!("Signed:       {}", license.signed);
+    }
+    match status {
+        LicenseStatus::Valid => Ok(()),
+        LicenseStatus::ExpiresSoon(days) => {
+            eprintln!("warning: the license expires in {} day(s)", days);
+            Ok(())
+        }
+        LicenseStatus::Expired => bail!("The license expired"),
+        LicenseStatus::Unknown => {
+            eprintln!("warning: the license end date can't be read, it may have expired");
+            Ok(())
+        }
+    }
+}
+
+fn search_path(hub: &mut Hub, command: SearchPathCommand) -> anyhow::Result<()> {
+    match command {
+        SearchPathCommand::List => {
+            let mut table = Table::new(["ENABLED", "PATH", "RESOLVED"]);
+            for search_path in &hub.config.unity_search_paths {
+                table.row([
+                    search_path.enabled.to_string(),
+                    search_path.path.clone(),
+                    search_path.resolved().to_string_lossy().to_string(),
+                ]);
+            }
+            table.print();
+            return Ok(());
+        }
+        SearchPathCommand::Add { path } => {
+            let path = paths::contract(&absolute(&paths::expand(&path))?.to_string_lossy());
+            if hub.config.unity_search_paths.iter().any(|p| p.path == path) {
+                bail!("{} is already a search path", path);
+            }
+            hub.config.unity_search_paths.push(SearchPath::new(&path));
+        }
+        SearchPathCommand::Remove { path } => {
+            let contracted = paths::contract(&path);
+            let before = hub.config.unity_search_paths.len();
+            hub.config
+                .unity_search_paths
+                .retain(|p| p.path != path && p.path != contracted);
+            if hub.config.unity_search_paths.len() == before {
+                bail!("{} is not a search path", path);
+            }
+        }
+    }
+    hub.update_data();
+    store_hub(hub)?;
+    println!(
+        "{} editor(s) found",
+        hub.config.editors_configurations.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+/// Plain text table with columns padded to the widest cell.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<const N: usize>(header: [&str; N]) -> Self {
+        Self {
+            header: header.iter().map(|title| title.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row<const N: usize>(&mut self, cells: [String; N]) {
+        self.rows.push(cells.into());
+    }
+
+    pub fn print(&self) {
+        let mut widths: Vec<usize> = self
+            .header
+            .iter()
+            .map(|title| title.chars().count())
+            .collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        print_row(&self.header, &widths);
+        for row in &self.rows {
+            print_row(row, &widths);
+        }
+    }
+}
+
+fn print_row(cells: &[String], widths: &[usize]) {
+    let line = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect::<Vec<_>>()
+        .join("  ");
+    println!("{}", line.trim_end());
+}
@@ -0,0 +1,118 @@
+use crate::{consts::VERTICAL_SPACING, hub_client::open_in_file_manager};
+use eframe::{
+    egui::{self, Ui},
+    epaint::Color32,
+};
+use std::time::SystemTime;
+use unity_hub_lib::license::{self, LicenseError, LicenseStatus, UnityLicense};
+
+/// License of this machine shown by the Account tab.
+pub struct AccountPanel {
+    license: Result<UnityLicense, LicenseError>,
+}
+
+impl Default for AccountPanel {
+    fn default() -> Self {
+        Self {
+            license: license::read_license(&license::license_path()),
+        }
+    }
+}
+
+impl AccountPanel {
+    /// True when the license is about to expire, expired or its end date can't be read.
+    pub fn has_warning(&self) -> bool {
+        self.license
+            .as_ref()
+            .is_ok_and(|license| license.status(SystemTime::now()) != LicenseStatus::Valid)
+    }
+
+    /// License of this machine as activated by the editor or the Hub.
+    pub fn draw(&mut self, ui: &mut Ui) {
+        ui.label(egui::RichText::new("Unity license").heading());
+        ui.add_space(VERTICAL_SPACING);
+        let path = license::license_path();
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(path.to_string_lossy()).weak());
+            if ui.button("Reload").clicked() {
+                self.license = license::read_license(&path);
+            }
+            if let Some(dir) = path.parent().filter(|dir| dir.is_dir()) {
+                if ui.button("Open directory").clicked() {
+                    open_in_file_manager(&dir.to_string_lossy());
+                }
+            }
+        });
+        ui.add_space(VERTICAL_SPACING);
+
+        let license = match &self.license {
+            Ok(license) => license,
+            Err(err) => {
+                ui.label(egui::RichText::new(format!("⚠ {}", err)).color(Color32::YELLOW));
+                return;
+            }
+        };
+        match license.status(SystemTime::now()) {
+            LicenseStatus::Valid => {}
+            LicenseStatus::ExpiresSoon(days) => {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "⚠ The license expires in {} day(s), activate it again to keep using the editor",
+                        days
+                    ))
+                    .color(Color32::YELLOW),
+                );
+            }
+            LicenseStatus::Expired => {
+                ui.label(
+                    egui::RichText::new("✖ The license expired, the editor won't start")
+                        .color(Color32::RED),
+                );
+            }
+            LicenseStatus::Unknown => {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "⚠ The license end date {} can't be read, it may have expired",
+                        license.expires.as_deref().unwrap_or_default()
+                    ))
+                    .color(Color32::YELLOW),
+                );
+            }
+        }
+        let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        egui::Grid::new("license")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Type");
+                ui.label(egui::RichText::new(license.kind.name()).strong());
+                ui.end_row();
+                ui.label("Serial");
+                ui.label(&license.serial_masked);
+                ui.end_row();
+                ui.label("Activated");
+                ui.label(optional(&license.activated));
+                ui.end_row();
+                ui.label("Updated");
+                ui.label(optional(&license.updated));
+                ui.end_row();
+                ui.label("Expires");
+                ui.label(license.expires.as_deref().unwrap_or("never"));
+                ui.end_row();
+                ui.label("Machine ID");
+                ui.label(optional(&license.machine_id));
+                ui.end_row();
+                for binding in &license.bindings {
+                    ui.label(format!("Binding {}", binding.key));
+                    ui.label(&binding.value);
+                    ui.end_row();
+                }
+                ui.label("Entitlements");
+                ui.label(license.entitlements.join(", "));
+                ui.end_row();
+                ui.label("Signed");
+                ui.label(if license.signed { "yes" } else { "no" });
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    hub_client::{split_patterns, text_setting},
+    jobs_panel::JobsPanel,
+};
+use eframe::{
+    egui::{self, Ui},
+    epaint::Color32,
+};
+use std::collections::HashSet;
+use unity_hub_lib::{
+    build_profile::{BuildProfile, BuildTarget},
+    hub::Hub,
+    unity_project::UnityProject,
+};
+
+/// Outcome of drawing the build profiles window.
+#[derive(Default)]
+pub struct BuildsResponse {
+    /// Profiles were edited and the config has to be stored.
+    pub changed: bool,
+    /// At least one build was queued.
+    pub queued: bool,
+}
+
+/// Build profiles of a single project edited in a separate window.
+#[derive(Default)]
+pub struct BuildsPanel {
+    /// Path of the project which build profiles are edited.
+    project: Option<String>,
+    /// Indexes of the profiles built by "Build selected".
+    selected: HashSet<usize>,
+}
+
+impl BuildsPanel {
+    pub fn open(&mut self, project: &str) {
+        self.project = Some(project.to_string());
+        self.selected.clear();
+    }
+
+    /// Build profiles of the open project with buttons queueing them.
+    pub fn draw(
+        &mut self,
+        ctx: &egui::Context,
+        hub: &mut Hub,
+        jobs: &mut JobsPanel,
+    ) -> BuildsResponse {
+        let mut response = BuildsResponse::default();
+        let Some(path) = &self.project else {
+            return response;
+        };
+        let Some(index) = hub.projects.iter().position(|p| &p.path == path) else {
+            self.project = None;
+            return response;
+        };
+        let mut open = true;
+        let mut build = Vec::new();
+        let mut remove = None;
+        let project = &mut hub.projects[index];
+        let selected_profiles = &mut self.selected;
+        let changed = &mut response.changed;
+        egui::Window::new(format!("Build profiles of {}", project.title))
+            .open(&mut open)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                if project.build_profiles.is_empty() {
+                    ui.label("No build profiles yet.");
+                }
+                for (i, profile) in project.build_profiles.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        let mut selected = selected_profiles.contains(&i);
+                        if ui.checkbox(&mut selected, "").changed() {
+                            if selected {
+                                selected_profiles.insert(i);
+                            } else {
+                                selected_profiles.remove(&i);
+                            }
+                        }
+                        ui.label(egui::RichText::new(&profile.name).strong());
+                        ui.label(egui::RichText::new(profile.target.name()).weak());
+                        if let Err(err) = profile.validate() {
+                            ui.label(egui::RichText::new("⚠").color(Color32::YELLOW))
+                                .on_hover_text(err.to_string());
+                        }
+                        if ui.button("▶ Build").clicked() {
+                            build.push(i);
+                        }
+                        if ui.button("🗑").on_hover_text("Remove profile").clicked() {
+                            remove = Some(i);
+                        }
+                    });
+                    egui::CollapsingHeader::new("Settings")
+                        .id_salt(("build_profile", i))
+                        .show(ui, |ui| {
+                            *changed |= draw_build_profile(ui, i, profile);
+                        });
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.menu_button("➕ Add profile", |ui| {
+                        for target in BuildTarget::ALL {
+                            if ui.button(target.name()).clicked() {
+                                project.build_profiles.push(BuildProfile::new(target));
+                                *changed = true;
+                                ui.close();
+                            }
+                        }
+                    });
+                    if ui
+                        .add_enabled(
+                            !selected_profiles.is_empty(),
+                            egui::Button::new("▶ Build selected"),
+                        )
+                        .clicked()
+                    {
+                        build.extend(selected_profiles.iter().copied());
+                        build.sort();
+                    }
+                });
+            });
+
+        if let Some(i) = remove {
+            project.build_profiles.remove(i);
+            self.selected.clear();
+            response.changed = true;
+        }
+        let project = project.clone();
+        if !open {
+            self.project = None;
+            self.selected.clear();
+        }
+        if !build.is_empty() {
+            let profiles: Vec<BuildProfile> = build
+                .iter()
+                .filter_map(|i| project.build_profiles.get(*i).cloned())
+                .collect();
+            response.queued = queue_builds(hub, jobs, &project, &profiles);
+        }
+        response
+    }
+}
+
+/// Queues builds of `project` one after another, reporting profiles that can't be built.
+/// Returns true when any of them was queued.
+fn queue_builds(
+    hub: &Hub,
+    jobs: &mut JobsPanel,
+    project: &UnityProject,
+    profiles: &[BuildProfile],
+) -> bool {
+    let mut errors = Vec::new();
+    for profile in profiles {
+        match hub.build_job(project, profile) {
+            Ok(job) => {
+                jobs.queue(job, Some(profile.output_for(project)));
+            }
+            Err(err) => errors.push(format!("{}: {}", profile.name, err)),
+        }
+    }
+    if !errors.is_empty() {
+        rfd::MessageDialog::new()
+            .set_title("Some builds were not queued")
+            .set_description(errors.join("\n"))
+            .set_level(rfd::MessageLevel::Warning)
+            .show();
+    }
+    errors.len() < profiles.len()
+}
+
+/// Draws editable settings of a build profile, returns true when any of them changed.
+fn draw_build_profile(ui: &mut Ui, index: usize, profile: &mut BuildProfile) -> bool {
+    let mut changed = false;
+    egui::Grid::new(("build_profile_grid", index))
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Name");
+            changed |= text_setting(ui, ("profile_name", index), &mut profile.name);
+            ui.end_row();
+
+            ui.label("Target");
+            egui::ComboBox::from_id_salt(("profile_target", index))
+                .selected_text(profile.target.name())
+                .show_ui(ui, |ui| {
+                    for target in BuildTarget::ALL {
+                        changed |= ui
+                            .selectable_value(&mut profile.target, target, target.name())
+                            .changed();
+                    }
+                });
+            ui.end_row();
+
+            ui.label("Output");
+            changed |= text_setting(ui, ("profile_output", index), &mut profile.output_path);
+            ui.end_row();
+
+            ui.label("Build method");
+            changed |= text_setting(ui, ("profile_method", index), &mut profile.build_method);
+            ui.end_row();
+
+            ui.label("Defines");
+            let mut defines = profile.scripting_defines.join(", ");
+            if text_setting(ui, ("profile_defines", index), &mut defines) {
+                profile.scripting_defines = split_patterns(&defines);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("");
+            changed |= ui
+                .checkbox(&mut profile.development, "Development build")
+                .changed();
+            ui.end_row();
+
+            ui.label("Arguments");
+            let mut args = profile.extra_args.join(" ");
+            if text_setting(ui, ("profile_args", index), &mut args) {
+                profile.extra_args = args.split_whitespace().map(str::to_string).collect();
+                changed = true;
+            }
+            ui.end_row();
+        });
+    changed
+}
@@ -1,8 +1,13 @@
 use crate::{
+    account_panel::AccountPanel,
+    builds_panel::BuildsPanel,
     consts::HOMEPAGE,
     consts::{
         APP_NAME, HEADER_HEIGHT, TOP_BUTTON_WIDTH, TOP_SIDE_MARGIN, VERSION, VERTICAL_SPACING,
     },
+    jobs_panel::JobsPanel,
+    logs_panel::LogsPanel,
+    tests_panel,
     window_tab::WindowTab,
 };
 use eframe::{
@@ -12,11 +17,222 @@ use eframe::{
 use egui_extras::{Column, TableBuilder};
 use inline_tweak::*;
 use rfd::FileDialog;
-use unity_hub_lib::{consts::FILE_MANAGER, hub::Hub};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant, SystemTime};
+use unity_hub_lib::{
+    architecture::Architecture,
+    config::Configuration,
+    consts::FILE_MANAGER,
+    discovery, editor_log,
+    editor_module::EditorModule,
+    editor_process::{EditorExit, EditorProcesses},
+    hub::Hub,
+    installer::{
+        self, ArchiveKind, ArchiveSource, InstallError, InstallProgress, InstallRequest,
+        InstallStage,
+    },
+    module_installer, paths, process,
+    project_root::ProjectRoot,
+    project_scan::{self, CancelToken, ScanEvent, ScanOptions, ScanReport},
+    release_catalog::{CatalogError, EditorRelease, ReleaseCatalog},
+    search_path::SearchPath,
+    test_runner::TestPlatform,
+    uninstall::{self, UninstallError, UninstallProgress},
+    unity_editor::UnityEditor,
+    unity_project::UnityProject,
+    watcher::{HubWatcher, WatchEvent},
+};
+
+/// How long file changes have to settle before they are applied, installs touch many files.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
 
 pub struct HubClient {
     hub: Hub,
     current_tab: WindowTab,
+    /// Executable of the editor which modules are displayed in a separate window.
+    modules_window: Option<String>,
+    host_architecture: Architecture,
+    editor_sort: EditorSort,
+    /// Sizes of editors computed by the background thread, keyed by executable path.
+    editor_sizes: Option<Receiver<(String, u64)>>,
+    uninstall_task: Option<UninstallTask>,
+    /// Editor versions published in the release catalog, newest first.
+    releases: Vec<EditorRelease>,
+    releases_task: Option<Receiver<CatalogEvent>>,
+    releases_progress: (usize, usize),
+    releases_error: Option<String>,
+    show_prereleases: bool,
+    install_dialog: Option<InstallDialog>,
+    install_task: Option<InstallTask>,
+    update_task: Option<UpdateTask>,
+    project_scan: Option<ProjectScan>,
+    project_locate: Option<ProjectLocate>,
+    show_hidden_projects: bool,
+    /// `None` when the platform file watcher couldn't be created.
+    watcher: Option<HubWatcher>,
+    watch_events: Receiver<WatchEvent>,
+    /// Changes waiting for `WATCH_DEBOUNCE` to pass since the last one arrived.
+    pending_changes: HashSet<WatchEvent>,
+    last_change: Instant,
+    jobs: JobsPanel,
+    builds: BuildsPanel,
+    logs: LogsPanel,
+    /// Editors opened from the hub.
+    editors: EditorProcesses,
+    /// Editors that crashed and weren't dismissed yet.
+    editor_crashes: Vec<EditorExit>,
+    account: AccountPanel,
+    ctx: egui::Context,
+}
+
+enum UpdateEvent {
+    Scan(ScanEvent),
+    Finished(Hub),
+}
+
+/// Editors and projects rescanned on a worker thread, see `HubClient::start_update`.
+struct UpdateTask {
+    cancel: CancelToken,
+    /// Paths of the projects the scan started with.
+    scanned_from: HashSet<String>,
+    /// Directory of a project root being scanned, `None` while editors are scanned.
+    current: Option<PathBuf>,
+    found: usize,
+    events: Receiver<UpdateEvent>,
+}
+
+enum ProjectScanEvent {
+    Scan(ScanEvent),
+    Finished(ScanReport),
+}
+
+struct ProjectScan {
+    root: PathBuf,
+    /// Started by the watcher after `root` changed, it ends without a report.
+    watched: bool,
+    current: PathBuf,
+    found: usize,
+    /// Found projects that were already in the list.
+    known: usize,
+    cancel: CancelToken,
+    events: Receiver<ProjectScanEvent>,
+}
+
+/// Search for a moved project on a worker thread, see `HubClient::locate_project`.
+struct ProjectLocate {
+    project: UnityProject,
+    dir: String,
+    cancel: CancelToken,
+    result: Receiver<Vec<UnityProject>>,
+}
+
+/// Change to the project list requested from a project row.
+enum ProjectAction {
+    Open(Box<(UnityEditor, UnityProject)>),
+    Kill(String),
+    SetHidden(String, bool),
+    Remove(String),
+    Locate(UnityProject),
+    RunTests(UnityProject, TestPlatform),
+}
+
+/// Editor install waiting for the user to pick the destination.
+struct InstallDialog {
+    title: String,
+    source: ArchiveSource,
+    /// Known for releases from the catalog, picked archives are recognized while installing.
+    version: Option<String>,
+    checksum: String,
+    /// Index of the chosen search path.
+    destination: usize,
+}
+
+enum InstallEvent {
+    /// Name of the module being installed.
+    Step(String),
+    Progress(InstallProgress),
+    Finished(Result<PathBuf, InstallError>),
+}
+
+struct InstallTask {
+    title: String,
+    step: Option<String>,
+    progress: Option<InstallProgress>,
+    events: Receiver<InstallEvent>,
+}
+
+enum CatalogEvent {
+    Progress(usize, usize),
+    Finished(Result<Vec<EditorRelease>, CatalogError>),
+}
+
+enum UninstallEvent {
+    Progress(UninstallProgress),
+    Finished(Result<(), UninstallError>),
+}
+
+/// Editor being removed by a background thread.
+struct UninstallTask {
+    version: String,
+    progress: UninstallProgress,
+    events: Receiver<UninstallEvent>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EditorColumn {
+    Version,
+    Release,
+    Size,
+    Installed,
+}
+
+#[derive(Clone, Copy)]
+struct EditorSort {
+    column: EditorColumn,
+    ascending: bool,
+}
+
+impl Default for EditorSort {
+    fn default() -> Self {
+        Self {
+            column: EditorColumn::Version,
+            ascending: false,
+        }
+    }
+}
+
+impl EditorSort {
+    /// Indices of `editors` in display order, the stored order is left alone.
+    fn order(&self, editors: &[UnityEditor]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..editors.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = if self.ascending {
+                (&editors[a], &editors[b])
+            } else {
+                (&editors[b], &editors[a])
+            };
+            self.compare(a, b)
+        });
+        order
+    }
+
+    /// Compares by the sort column, then by version and path so equal rows keep their place.
+    fn compare(&self, a: &UnityEditor, b: &UnityEditor) -> std::cmp::Ordering {
+        let by_column = match self.column {
+            EditorColumn::Version => std::cmp::Ordering::Equal,
+            EditorColumn::Release => {
+                let track = |editor: &UnityEditor| editor.unity_version().map(|v| v.track());
+                track(a).cmp(&track(b))
+            }
+            EditorColumn::Size => a.installed_size.cmp(&b.installed_size),
+            EditorColumn::Installed => a.install_date.cmp(&b.install_date),
+        };
+        by_column
+            .then_with(|| a.unity_version().cmp(&b.unity_version()))
+            .then_with(|| a.exe_path.cmp(&b.exe_path))
+    }
 }
 
 fn setup_custom_fonts(ctx: &egui::Context) {
@@ -89,27 +305,855 @@ fn get_fonts() -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
 impl HubClient {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         setup_custom_fonts(&cc.egui_ctx);
-        let hub_option = confy::load("rusty_hub_egui", "config");
+        // Cached data is shown right away, rescanning happens in the background. Without a
+        // config `confy` would build `Hub::default`, which scans editors before the first frame.
+        let saved = confy::get_configuration_file_path("rusty_hub_egui", "config")
+            .is_ok_and(|path| path.is_file());
+        let hub = saved
+            .then(|| confy::load::<Hub>("rusty_hub_egui", "config").ok())
+            .flatten()
+            .unwrap_or_else(|| Hub::new(Configuration::unscanned(), Vec::new()));
 
-        let hub = if hub_option.is_ok() {
-            let mut h: Hub = hub_option.unwrap();
-            h.update_data();
-            h
-        } else {
-            Hub::default()
-        };
+        let releases = release_catalog(&hub)
+            .cached()
+            .map(|(releases, _)| releases)
+            .unwrap_or_default();
 
-        Self {
+        let (sender, watch_events) = mpsc::channel();
+        let ctx = cc.egui_ctx.clone();
+        let watcher = HubWatcher::new(move |event| {
+            let _ = sender.send(event);
+            ctx.request_repaint();
+        })
+        .ok();
+
+        let mut client = Self {
             hub,
             current_tab: WindowTab::Projects,
+            modules_window: None,
+            host_architecture: Architecture::host(),
+            editor_sort: EditorSort::default(),
+            editor_sizes: None,
+            uninstall_task: None,
+            releases,
+            releases_task: None,
+            releases_progress: (0, 0),
+            releases_error: None,
+            show_prereleases: false,
+            install_dialog: None,
+            install_task: None,
+            update_task: None,
+            project_scan: None,
+            project_locate: None,
+            show_hidden_projects: false,
+            watcher,
+            watch_events,
+            pending_changes: HashSet::new(),
+            last_change: Instant::now(),
+            jobs: JobsPanel::new(&cc.egui_ctx),
+            builds: BuildsPanel::default(),
+            logs: LogsPanel::default(),
+            editors: EditorProcesses::default(),
+            editor_crashes: Vec::new(),
+            account: AccountPanel::default(),
+            ctx: cc.egui_ctx.clone(),
+        };
+        client.sync_watcher();
+        client.start_update(false);
+        client
+    }
+
+    /// Rescans editors and projects on a worker thread, replacing a rescan that's still running.
+    fn start_update(&mut self, force: bool) {
+        if let Some(task) = self.update_task.take() {
+            task.cancel.cancel();
         }
+        let mut hub = self.hub.clone();
+        let cancel = CancelToken::default();
+        let (sender, receiver) = mpsc::channel();
+        let ctx = self.ctx.clone();
+        let cancelled = cancel.clone();
+        let scanned_from = hub.projects.iter().map(|p| p.path.clone()).collect();
+        std::thread::spawn(move || {
+            hub.update_data_until(force, &cancelled, |event| {
+                let _ = sender.send(UpdateEvent::Scan(event));
+                ctx.request_repaint();
+            });
+            if !cancelled.is_cancelled() {
+                let _ = sender.send(UpdateEvent::Finished(hub));
+                ctx.request_repaint();
+            }
+        });
+        self.update_task = Some(UpdateTask {
+            cancel,
+            scanned_from,
+            current: None,
+            found: 0,
+            events: receiver,
+        });
     }
 
-    fn save_config(&mut self, rebuild: bool) {
+    fn receive_update(&mut self) {
+        let Some(task) = &mut self.update_task else {
+            return;
+        };
+        let hub = loop {
+            match task.events.try_recv() {
+                Ok(UpdateEvent::Scan(ScanEvent::Entered(dir))) => task.current = Some(dir),
+                // Found projects show up right away, their details come with the finished hub.
+                Ok(UpdateEvent::Scan(ScanEvent::Found(project))) => {
+                    if !self.hub.projects.contains(&project) && !self.hub.is_ignored(&project) {
+                        task.found += 1;
+                        self.hub.add_projects([project]);
+                    }
+                }
+                Ok(UpdateEvent::Scan(_)) => {}
+                Ok(UpdateEvent::Finished(hub)) => break hub,
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    self.update_task = None;
+                    return;
+                }
+            }
+        };
+        let Some(task) = self.update_task.take() else {
+            return;
+        };
+
+        // Settings could have changed during the scan, only take what was scanned.
+        let config = &mut self.hub.config;
+        config.editors_configurations = hub.config.editors_configurations;
+        config.search_reports = hub.config.search_reports;
+        config.discovery_reports = hub.config.discovery_reports;
+        config.project_root_reports = hub.config.project_root_reports;
+        self.hub
+            .merge_scanned_projects(hub.projects, &task.scanned_from);
+        let _ = confy::store("rusty_hub_egui", "config", &self.hub);
+        self.sync_watcher();
+    }
+
+    fn sync_watcher(&mut self) {
+        if let Some(watcher) = &mut self.watcher {
+            watcher.sync(&self.hub);
+        }
+    }
+
+    /// Applies file changes reported by the watcher once they stop coming in.
+    fn receive_watch_events(&mut self) {
+        while let Ok(event) = self.watch_events.try_recv() {
+            self.pending_changes.insert(event);
+            self.last_change = Instant::now();
+        }
+        if self.pending_changes.is_empty() {
+            return;
+        }
+        let elapsed = self.last_change.elapsed();
+        if elapsed < WATCH_DEBOUNCE {
+            self.ctx.request_repaint_after(WATCH_DEBOUNCE - elapsed);
+            return;
+        }
+
+        let mut rebuild = false;
+        let mut changed = false;
+        for event in std::mem::take(&mut self.pending_changes) {
+            match event {
+                WatchEvent::SearchPathChanged(_) => rebuild = true,
+                WatchEvent::ProjectRootChanged(root) if self.project_scan.is_none() => {
+                    self.start_project_scan(root, true)
+                }
+                // One scan runs at a time, the root waits for the running one.
+                event @ WatchEvent::ProjectRootChanged(_) => {
+                    self.pending_changes.insert(event);
+                }
+                event => changed |= self.hub.apply_watch_event(&event),
+            }
+        }
+        if !self.pending_changes.is_empty() {
+            self.ctx.request_repaint_after(WATCH_DEBOUNCE);
+        }
         if rebuild {
-            self.hub.update_data();
+            self.start_update(false);
+        } else if changed {
+            self.save_config(false);
+        }
+    }
+
+    /// Looks for projects in `root` on a worker thread, replacing the running scan.
+    fn start_project_scan(&mut self, root: PathBuf, watched: bool) {
+        if let Some(scan) = self.project_scan.take() {
+            scan.cancel.cancel();
+            if scan.watched {
+                self.pending_changes
+                    .insert(WatchEvent::ProjectRootChanged(scan.root));
+            }
+        }
+        let cancel = CancelToken::default();
+        let (sender, receiver) = mpsc::channel();
+        let ctx = self.ctx.clone();
+        let cancelled = cancel.clone();
+        let options = self.hub.config.project_scan.clone();
+        let scanned = root.clone();
+        std::thread::spawn(move || {
+            let report = project_scan::scan_projects(&scanned, &options, &cancelled, |event| {
+                let _ = sender.send(ProjectScanEvent::Scan(event));
+                ctx.request_repaint();
+            });
+            let _ = sender.send(ProjectScanEvent::Finished(report));
+            ctx.request_repaint();
+        });
+        self.project_scan = Some(ProjectScan {
+            root,
+            watched,
+            current: PathBuf::new(),
+            found: 0,
+            known: 0,
+            cancel,
+            events: receiver,
+        });
+    }
+
+    fn receive_project_scan(&mut self) {
+        let Some(scan) = &mut self.project_scan else {
+            return;
+        };
+        let mut finished = None;
+        while let Ok(event) = scan.events.try_recv() {
+            match event {
+                ProjectScanEvent::Scan(ScanEvent::Entered(dir)) => scan.current = dir,
+                ProjectScanEvent::Scan(ScanEvent::Found(project)) => {
+                    if self.hub.projects.contains(&project) || self.hub.is_ignored(&project) {
+                        scan.known += 1;
+                    } else {
+                        scan.found += 1;
+                        self.hub.add_projects([project]);
+                    }
+                }
+                ProjectScanEvent::Scan(_) => {}
+                ProjectScanEvent::Finished(report) => finished = Some(report),
+            }
+        }
+
+        let Some(mut report) = finished else {
+            return;
+        };
+        report.found = scan.found;
+        report.duplicates += scan.known;
+        let Some(scan) = self.project_scan.take() else {
+            return;
+        };
+        if scan.watched {
+            let event = WatchEvent::ProjectRootChanged(scan.root);
+            if self.hub.apply_watch_event(&event) || report.found > 0 {
+                self.save_config(false);
+            }
+            return;
+        }
+        self.save_config(false);
+
+        let mut details = format!(
+            "{} duplicate(s), {} folder(s) skipped.",
+            report.duplicates, report.skipped
+        );
+        if !report.errors.is_empty() {
+            details.push_str(&format!(
+                "\n{} error(s):\n{}",
+                report.errors.len(),
+                report.errors.join("\n")
+            ));
+        }
+        let mut message = rfd::MessageDialog::new().set_title("Search ended");
+        match (report.cancelled, report.found) {
+            (true, found) => {
+                message = message.set_description(format!(
+                    "Search cancelled, {} new project(s) added.\n{}",
+                    found, details
+                ))
+            }
+            (false, 0) => {
+                message = message
+                    .set_description(format!("No new projects found.\n{}", details))
+                    .set_level(rfd::MessageLevel::Warning)
+            }
+            (false, 1) => {
+                message = message.set_description(format!("Project founded!\n{}", details))
+            }
+            (false, found) => {
+                message =
+                    message.set_description(format!("Founded {} projects.\n{}", found, details))
+            }
+        }
+        message.show();
+    }
+
+    /// Spinner with the running scan and a button cancelling it.
+    fn draw_scan_status(&mut self, ui: &mut Ui) {
+        if let Some(scan) = &self.project_scan {
+            ui.spinner();
+            ui.label(
+                egui::RichText::new(format!(
+                    "Scanning {} ({} found)",
+                    scan.current.to_string_lossy(),
+                    scan.found
+                ))
+                .small(),
+            );
+            if ui.button("✖ Cancel").clicked() {
+                scan.cancel.cancel();
+            }
+        } else if let Some(locate) = &self.project_locate {
+            ui.spinner();
+            ui.label(
+                egui::RichText::new(format!(
+                    "Looking for {} in {}",
+                    locate.project.title, locate.dir
+                ))
+                .small(),
+            );
+            if ui.button("✖ Cancel").clicked() {
+                locate.cancel.cancel();
+                self.project_locate = None;
+            }
+        } else if let Some(task) = &self.update_task {
+            ui.spinner();
+            let status = match &task.current {
+                Some(dir) => format!("Scanning {} ({} found)", dir.to_string_lossy(), task.found),
+                None => "Scanning editors".to_string(),
+            };
+            ui.label(egui::RichText::new(status).small());
+            if ui.button("✖ Cancel").clicked() {
+                task.cancel.cancel();
+                self.update_task = None;
+            }
+        }
+    }
+
+    fn draw_install_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = &mut self.install_dialog else {
+            return;
+        };
+        let search_paths: Vec<&SearchPath> = self
+            .hub
+            .config
+            .unity_search_paths
+            .iter()
+            .filter(|search_path| search_path.enabled)
+            .collect();
+
+        let mut open = true;
+        let mut install = false;
+        egui::Window::new(format!("Install {}", dialog.title))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                if search_paths.is_empty() {
+                    ui.label("Add an editor search path to install editors into.");
+                    return;
+                }
+                egui::Grid::new("install_dialog")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Source");
+                        ui.label(match &dialog.source {
+                            ArchiveSource::File(path) => path.to_string_lossy().to_string(),
+                            ArchiveSource::Url(url) => url.clone(),
+                        });
+                        ui.end_row();
+
+                        ui.label("Search path");
+                        dialog.destination = dialog.destination.min(search_paths.len() - 1);
+                        egui::ComboBox::from_id_salt("install_destination")
+                            .selected_text(search_paths[dialog.destination].display_name())
+                            .show_ui(ui, |ui| {
+                                for (i, search_path) in search_paths.iter().enumerate() {
+                                    ui.selectable_value(
+                                        &mut dialog.destination,
+                                        i,
+                                        search_path.display_name(),
+                                    );
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label("Checksum")
+                            .on_hover_text("MD5 or SHA-256 hex, or sha384-... integrity string");
+                        ui.text_edit_singleline(&mut dialog.checksum);
+                        ui.end_row();
+                    });
+                ui.add_space(VERTICAL_SPACING);
+                install = ui
+                    .add_enabled(self.install_task.is_none(), egui::Button::new("📦 Install"))
+                    .clicked();
+            });
+
+        if install {
+            let request = InstallRequest {
+                source: dialog.source.clone(),
+                checksum: Some(dialog.checksum.clone()),
+                destination: search_paths[dialog.destination].resolved(),
+                mirror: Some(self.hub.config.download_mirror.clone()),
+                version: dialog.version.clone(),
+            };
+            let title = dialog.title.clone();
+            self.install_dialog = None;
+            self.start_install(title, request, ctx);
+        } else if !open {
+            self.install_dialog = None;
+        }
+    }
+
+    fn start_install(&mut self, title: String, request: InstallRequest, ctx: &egui::Context) {
+        let (sender, receiver) = mpsc::channel();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let result = installer::install_editor(&request, |progress| {
+                let _ = sender.send(InstallEvent::Progress(progress));
+                ctx.request_repaint();
+            });
+            let _ = sender.send(InstallEvent::Finished(result));
+            ctx.request_repaint();
+        });
+        self.install_task = Some(InstallTask {
+            title,
+            step: None,
+            progress: None,
+            events: receiver,
+        });
+    }
+
+    /// Installs the module with `id` together with its sub modules into the editor.
+    fn start_module_install(
+        &mut self,
+        editor: UnityEditor,
+        modules: &[EditorModule],
+        id: &str,
+        ctx: &egui::Context,
+    ) {
+        let modules = module_installer::modules_to_install(modules, id);
+        let Some(module) = modules.iter().find(|module| module.id == id) else {
+            return;
+        };
+        let title = format!("{} for {}", module.name, editor.version);
+        let mirror = self.hub.config.download_mirror.clone();
+        let (sender, receiver) = mpsc::channel();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let mut current = String::new();
+            let result = module_installer::install_modules(
+                &editor,
+                &modules,
+                Some(&mirror),
+                |module, progress| {
+                    if module.id != current {
+                        current = module.id.clone();
+                        let _ = sender.send(InstallEvent::Step(module.name.clone()));
+                    }
+                    let _ = sender.send(InstallEvent::Progress(progress));
+                    ctx.request_repaint();
+                },
+            );
+            let result = result.map(|_| PathBuf::from(&editor.base_path));
+            let _ = sender.send(InstallEvent::Finished(result));
+            ctx.request_repaint();
+        });
+        self.install_task = Some(InstallTask {
+            title,
+            step: None,
+            progress: None,
+            events: receiver,
+        });
+    }
+
+    fn draw_install_window(&mut self, ctx: &egui::Context) {
+        let Some(task) = &mut self.install_task else {
+            return;
+        };
+        let mut finished = None;
+        while let Ok(event) = task.events.try_recv() {
+            match event {
+                InstallEvent::Step(step) => task.step = Some(step),
+                InstallEvent::Progress(progress) => task.progress = Some(progress),
+                InstallEvent::Finished(result) => finished = Some(result),
+            }
+        }
+
+        let progress = task.progress;
+        let step = task.step.clone();
+        egui::Window::new(format!("Installing {}", task.title))
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| match progress {
+                Some(progress) if progress.total > 0 => {
+                    if let Some(step) = &step {
+                        ui.label(egui::RichText::new(step).strong());
+                    }
+                    ui.label(progress.stage.name());
+                    ui.add(
+                        egui::ProgressBar::new(progress.done as f32 / progress.total as f32)
+                            .show_percentage(),
+                    );
+                    if progress.stage != InstallStage::Extracting {
+                        ui.label(format!(
+                            "{} of {}",
+                            format_size(progress.done),
+                            format_size(progress.total)
+                        ));
+                    }
+                }
+                Some(progress) => {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(progress.stage.name());
+                    });
+                }
+                None => {
+                    ui.spinner();
+                }
+            });
+
+        let Some(result) = finished else {
+            return;
+        };
+        let title = task.title.clone();
+        self.install_task = None;
+        match result {
+            Ok(_) => self.save_config(true),
+            Err(err) => {
+                rfd::MessageDialog::new()
+                    .set_title(format!("Failed to install {}", title))
+                    .set_description(err.to_string())
+                    .set_level(rfd::MessageLevel::Error)
+                    .set_buttons(rfd::MessageButtons::Ok)
+                    .show();
+            }
+        }
+    }
+
+    fn refresh_releases(&mut self, ctx: &egui::Context) {
+        if self.releases_task.is_some() {
+            return;
+        }
+        let catalog = release_catalog(&self.hub);
+        let (sender, receiver) = mpsc::channel();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let result = catalog.refresh(|fetched, total| {
+                let _ = sender.send(CatalogEvent::Progress(fetched, total));
+                ctx.request_repaint();
+            });
+            let _ = sender.send(CatalogEvent::Finished(result));
+            ctx.request_repaint();
+        });
+        self.releases_task = Some(receiver);
+        self.releases_progress = (0, 0);
+        self.releases_error = None;
+    }
+
+    fn receive_releases(&mut self) {
+        let Some(receiver) = &self.releases_task else {
+            return;
+        };
+        while let Ok(event) = receiver.try_recv() {
+            match event {
+                CatalogEvent::Progress(fetched, total) => self.releases_progress = (fetched, total),
+                CatalogEvent::Finished(result) => {
+                    match result {
+                        Ok(releases) => self.releases = releases,
+                        Err(err) => self.releases_error = Some(err.to_string()),
+                    }
+                    self.releases_task = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    fn draw_available_editors(&mut self, ctx: &egui::Context, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Available editors").heading());
+            ui.add_space(TOP_SIDE_MARGIN);
+            if self.releases_task.is_some() {
+                ui.spinner();
+                let (fetched, total) = self.releases_progress;
+                ui.label(egui::RichText::new(format!("{} / {}", fetched, total)).small());
+            } else if ui.button("⟳ Refresh").clicked() {
+                self.refresh_releases(ctx);
+            }
+            ui.checkbox(&mut self.show_prereleases, "Show alpha and beta");
+        });
+        ui.horizontal(|ui| {
+            ui.label("Release catalog URL");
+            if text_setting(
+                ui,
+                "release_catalog_url",
+                &mut self.hub.config.release_catalog_url,
+            ) {
+                self.releases = release_catalog(&self.hub)
+                    .cached()
+                    .map(|(releases, _)| releases)
+                    .unwrap_or_default();
+                self.save_config(false);
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Download mirror").on_hover_text(
+                "URL or directory replacing the host of download URLs, empty to download from Unity",
+            );
+            if text_setting(ui, "download_mirror", &mut self.hub.config.download_mirror) {
+                self.save_config(false);
+            }
+        });
+        if let Some(err) = &self.releases_error {
+            ui.label(egui::RichText::new(format!("⚠ {}", err)).color(Color32::YELLOW));
+        }
+        ui.add_space(VERTICAL_SPACING);
+
+        let releases: Vec<&EditorRelease> = self
+            .releases
+            .iter()
+            .filter(|release| {
+                self.show_prereleases
+                    || !release
+                        .unity_version()
+                        .is_some_and(|version| version.stream.is_prerelease())
+            })
+            .collect();
+        if releases.is_empty() {
+            ui.label("No releases fetched yet.");
+            return;
+        }
+
+        let text_height = egui::TextStyle::Body.resolve(ui.style()).size * 2.0;
+        let installed = &self.hub.config.editors_configurations;
+        let mut install = None;
+        TableBuilder::new(ui)
+            .striped(true)
+            .vscroll(false)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::initial(120.0).at_least(120.0))
+            .column(Column::initial(80.0).at_least(80.0))
+            .column(Column::initial(100.0).at_least(100.0))
+            .column(Column::initial(90.0).at_least(90.0))
+            .column(Column::remainder())
+            .resizable(false)
+            .body(|body| {
+                body.rows(text_height, releases.len(), |mut row| {
+                    let release = releases[row.index()];
+                    let is_installed = installed
+                        .iter()
+                        .any(|editor| editor.version == release.version);
+                    row.col(|ui| {
+                        let text = egui::RichText::new(&release.version);
+                        if is_installed {
+                            ui.label(text.strong().color(Color32::GREEN))
+                                .on_hover_text("Installed");
+                        } else {
+                            ui.label(text);
+                        }
+                    });
+                    row.col(|ui| {
+                        ui.label(egui::RichText::new(&release.stream).small());
+                    });
+                    row.col(|ui| {
+                        ui.label(egui::RichText::new(release.date()).small());
+                    });
+                    row.col(|ui| {
+                        let size = release
+                            .host_download()
+                            .map(|download| format_size(download.download_size.bytes()));
+                        ui.label(
+                            egui::RichText::new(size.unwrap_or_else(|| "n/a".to_string())).small(),
+                        )
+                        .on_hover_text("Download size for this machine");
+                    });
+                    row.col(|ui| {
+                        if is_installed {
+                            ui.label(egui::RichText::new("✔ installed").small());
+                        } else if let Some(download) = release.host_download() {
+                            if ui.button("⬇ Install").clicked() {
+                                install = Some(InstallDialog {
+                                    title: release.version.clone(),
+                                    source: ArchiveSource::Url(download.url.clone()),
+                                    version: Some(release.version.clone()),
+                                    checksum: download.integrity.clone().unwrap_or_default(),
+                                    destination: 0,
+                                });
+                            }
+                        }
+                    });
+                });
+            });
+        if install.is_some() {
+            self.install_dialog = install;
+        }
+    }
+
+    /// Asks for confirmation, listing projects left without an editor, then removes the editor.
+    fn uninstall_editor(&mut self, editor: UnityEditor, ctx: &egui::Context) {
+        if self.uninstall_task.is_some() {
+            return;
+        }
+        let install = match uninstall::install_files(&editor) {
+            Ok(install) => install,
+            Err(err) => {
+                rfd::MessageDialog::new()
+                    .set_title("Uninstall editor")
+                    .set_description(err.to_string())
+                    .set_level(rfd::MessageLevel::Error)
+                    .show();
+                return;
+            }
+        };
+        let mut description = format!(
+            "Unity {} will be removed from disk together with {}.",
+            editor.version,
+            install.root.display()
+        );
+        let projects = self.hub.projects_using_editor(&editor);
+        if !projects.is_empty() {
+            description.push_str("\n\nThese projects will have no matching editor:");
+            for project in &projects {
+                description.push_str(&format!("\n • {} ({})", project.title, project.path));
+            }
+        }
+        let result = rfd::MessageDialog::new()
+            .set_title("Uninstall editor")
+            .set_description(description)
+            .set_level(rfd::MessageLevel::Warning)
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .show();
+        if result != rfd::MessageDialogResult::Yes {
+            return;
         }
+
+        let (sender, receiver) = mpsc::channel();
+        let version = editor.version.clone();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let result = Hub::uninstall_editor(&editor, |progress| {
+                let _ = sender.send(UninstallEvent::Progress(progress));
+                ctx.request_repaint();
+            });
+            let _ = sender.send(UninstallEvent::Finished(result));
+            ctx.request_repaint();
+        });
+        self.uninstall_task = Some(UninstallTask {
+            version,
+            progress: UninstallProgress::default(),
+            events: receiver,
+        });
+    }
+
+    fn draw_uninstall_window(&mut self, ctx: &egui::Context) {
+        let Some(task) = &mut self.uninstall_task else {
+            return;
+        };
+        let mut finished = None;
+        while let Ok(event) = task.events.try_recv() {
+            match event {
+                UninstallEvent::Progress(progress) => task.progress = progress,
+                UninstallEvent::Finished(result) => finished = Some(result),
+            }
+        }
+
+        let progress = task.progress;
+        egui::Window::new(format!("Uninstalling {}", task.version))
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                let fraction = if progress.total_files == 0 {
+                    0.0
+                } else {
+                    progress.removed_files as f32 / progress.total_files as f32
+                };
+                ui.add(egui::ProgressBar::new(fraction).show_percentage());
+                ui.label(format!(
+                    "{} of {} files removed",
+                    progress.removed_files, progress.total_files
+                ));
+            });
+
+        let Some(result) = finished else {
+            return;
+        };
+        self.uninstall_task = None;
+        if let Err(err) = result {
+            rfd::MessageDialog::new()
+                .set_title("Uninstall failed")
+                .set_description(err.to_string())
+                .set_level(rfd::MessageLevel::Error)
+                .set_buttons(rfd::MessageButtons::Ok)
+                .show();
+        }
+        self.save_config(true);
+    }
+
+    /// Starts computing sizes of editors that don't have one yet, one editor at a time.
+    fn compute_editor_sizes(&mut self, ctx: &egui::Context) {
+        if self.editor_sizes.is_some() {
+            return;
+        }
+        let pending: Vec<(String, String)> = self
+            .hub
+            .config
+            .editors_configurations
+            .iter()
+            .filter(|editor| editor.installed_size.is_none())
+            .map(|editor| (editor.exe_path.clone(), editor.base_path.clone()))
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            for (exe_path, base_path) in pending {
+                let size = UnityEditor::compute_installed_size(&base_path);
+                if sender.send((exe_path, size)).is_err() {
+                    return;
+                }
+                ctx.request_repaint();
+            }
+        });
+        self.editor_sizes = Some(receiver);
+    }
+
+    fn receive_editor_sizes(&mut self) {
+        let Some(receiver) = &self.editor_sizes else {
+            return;
+        };
+        loop {
+            match receiver.try_recv() {
+                Ok((exe_path, size)) => {
+                    let editor = self
+                        .hub
+                        .config
+                        .editors_configurations
+                        .iter_mut()
+                        .find(|editor| editor.exe_path == exe_path);
+                    if let Some(editor) = editor {
+                        editor.installed_size = Some(size);
+                    }
+                }
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    self.editor_sizes = None;
+                    self.save_config(false);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Stores the config, with `rebuild` editors and projects are rescanned in the background
+    /// and stored again once that's done.
+    fn save_config(&mut self, rebuild: bool) {
         let _ = confy::store("rusty_hub_egui", "config", &self.hub);
+        self.sync_watcher();
+        if rebuild {
+            self.start_update(false);
+        }
     }
 
     pub fn draw_central_panel(&mut self, ctx: &egui::Context) {
@@ -118,20 +1162,70 @@ impl HubClient {
                 match self.current_tab {
                     WindowTab::Projects => self.draw_project(ctx, ui),
                     WindowTab::Editors => self.draw_editors(ctx, ui),
+                    WindowTab::Jobs => self.jobs.draw(ui),
+                    WindowTab::Logs => {
+                        if self.logs.draw(ui, &mut self.hub.config.ide_command) {
+                            self.save_config(false);
+                        }
+                    }
+                    WindowTab::Account => self.account.draw(ui),
                 };
             });
         });
     }
 
-    fn draw_editors(&mut self, _ctx: &egui::Context, ui: &mut Ui) {
+    fn draw_editors(&mut self, ctx: &egui::Context, ui: &mut Ui) {
         ui.label(egui::RichText::new("Editor search paths").heading());
         ui.add_space(VERTICAL_SPACING);
         let text_height = egui::TextStyle::Body.resolve(ui.style()).size * 2.0;
 
         let paths = self.hub.config.unity_search_paths.clone();
-        for (i, path) in paths.iter().enumerate() {
+        let mut changed = false;
+        for (i, search_path) in paths.iter().enumerate() {
+            let resolved = search_path.resolved();
+            let exists = resolved.is_dir();
+            let report = self.hub.config.search_reports.get(&search_path.path);
             ui.horizontal(|ui| {
-                ui.label(path);
+                let status = if exists {
+                    egui::RichText::new("✔").color(Color32::GREEN)
+                } else {
+                    egui::RichText::new("✖").color(Color32::RED)
+                };
+                ui.label(status).on_hover_text(if exists {
+                    "Directory exists"
+                } else {
+                    "Directory does not exist"
+                });
+                let mut enabled = search_path.enabled;
+                if ui
+                    .checkbox(&mut enabled, "")
+                    .on_hover_text("Search this path")
+                    .changed()
+                {
+                    self.hub.config.unity_search_paths[i].enabled = enabled;
+                    changed = true;
+                }
+                ui.label(search_path.display_name())
+                    .on_hover_text(&search_path.path);
+                let resolved = resolved.to_string_lossy();
+                if resolved != search_path.path.as_str() {
+                    ui.label(
+                        egui::RichText::new(format!("→ {}", resolved))
+                            .small()
+                            .weak(),
+                    );
+                }
+                if let Some(report) = report {
+                    ui.label(egui::RichText::new(format!("{} editor(s)", report.editors)).small());
+                    if !report.errors.is_empty() {
+                        ui.label(
+                            egui::RichText::new(format!("⚠ {}", report.errors.len()))
+                                .small()
+                                .color(Color32::YELLOW),
+                        )
+                        .on_hover_text(report.errors.join("\n"));
+                    }
+                }
                 let height = tweak!(30.0);
                 let button_width = tweak!(100.0);
                 ui.allocate_space(egui::vec2(
@@ -143,77 +1237,315 @@ impl HubClient {
                     .clicked()
                 {
                     self.hub.config.unity_search_paths.remove(i);
-                    self.save_config(true);
+                    changed = true;
+                }
+            });
+            egui::CollapsingHeader::new("Options")
+                .id_salt(("search_path_options", i))
+                .show(ui, |ui| {
+                    if let Some(edited) = self.hub.config.unity_search_paths.get_mut(i) {
+                        changed |= draw_search_path_options(ui, i, edited);
+                    }
+                });
+            if changed {
+                break;
+            }
+        }
+        if changed {
+            self.save_config(true);
+        }
+        ui.add_space(VERTICAL_SPACING * 2.0);
+
+        self.draw_discovery(ui);
+        ui.add_space(VERTICAL_SPACING * 2.0);
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Installed editor versions").heading());
+            ui.add_space(TOP_SIDE_MARGIN);
+            if ui
+                .button("⟳ Force rescan")
+                .on_hover_text("Parse all editors again, ignoring cached information")
+                .clicked()
+            {
+                self.start_update(true);
+            }
+        });
+        ui.add_space(VERTICAL_SPACING);
+
+        let order = self
+            .editor_sort
+            .order(&self.hub.config.editors_configurations);
+
+        let table2 = TableBuilder::new(ui)
+            .striped(true)
+            .vscroll(false)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::initial(100.0).at_least(100.0).at_most(120.0))
+            .column(Column::initial(90.0).at_least(90.0).at_most(120.0))
+            .column(Column::initial(80.0).at_least(80.0).at_most(100.0))
+            .column(Column::initial(90.0).at_least(90.0).at_most(120.0))
+            .column(Column::initial(100.0).at_least(100.0).at_most(120.0))
+            .column(Column::initial(150.0).at_least(150.0).at_most(400.0))
+            .column(Column::remainder().at_least(260.0))
+            .resizable(false);
+
+        let mut show_modules = None;
+        let mut uninstall = None;
+        let mut sort = self.editor_sort;
+        let host_architecture = self.host_architecture;
+        let header = table2.header(text_height * 0.6, |mut header| {
+            header.col(|ui| sort_header(ui, &mut sort, EditorColumn::Version, "Version"));
+            header.col(|ui| {
+                ui.label(egui::RichText::new("Arch").strong());
+            });
+            header.col(|ui| sort_header(ui, &mut sort, EditorColumn::Release, "Release"));
+            header.col(|ui| sort_header(ui, &mut sort, EditorColumn::Size, "Size"));
+            header.col(|ui| sort_header(ui, &mut sort, EditorColumn::Installed, "Installed"));
+            header.col(|ui| {
+                ui.label(egui::RichText::new("Platforms").strong());
+            });
+            header.col(|ui| {
+                ui.label(egui::RichText::new("Path").strong());
+            });
+        });
+        header.body(|body| {
+            body.rows(text_height, order.len(), |mut row| {
+                let editor = &self.hub.config.editors_configurations[order[row.index()]];
+                row.col(|ui| {
+                    ui.vertical_centered_justified(|ui| {
+                        ui.add_space(VERTICAL_SPACING);
+                        ui.label(&editor.version)
+                            .on_hover_text(format!("{} editor", editor.layout.name()));
+                    });
+                });
+                row.col(|ui| {
+                    ui.vertical_centered_justified(|ui| {
+                        ui.add_space(VERTICAL_SPACING);
+                        let native = editor.architecture.runs_natively_on(host_architecture);
+                        let text = egui::RichText::new(editor.architecture.name()).small();
+                        if native {
+                            ui.label(text);
+                        } else {
+                            ui.label(text.color(Color32::YELLOW)).on_hover_text(format!(
+                                "Does not run natively on this {} machine",
+                                host_architecture.name()
+                            ));
+                        }
+                    });
+                });
+                row.col(|ui| {
+                    ui.vertical_centered_justified(|ui| {
+                        ui.add_space(VERTICAL_SPACING);
+                        match editor.unity_version() {
+                            Some(version) => {
+                                let text = egui::RichText::new(version.track()).small();
+                                let text = if version.is_lts() {
+                                    text.strong().color(Color32::GREEN)
+                                } else {
+                                    text
+                                };
+                                ui.label(text)
+                                    .on_hover_text(format!("{} release", version.stream.name()));
+                            }
+                            None => {
+                                ui.label(egui::RichText::new("unknown").small());
+                            }
+                        }
+                    });
+                });
+                row.col(|ui| {
+                    ui.vertical_centered_justified(|ui| {
+                        ui.add_space(VERTICAL_SPACING);
+                        match editor.installed_size {
+                            Some(size) => ui.label(egui::RichText::new(format_size(size)).small()),
+                            None => ui.spinner().on_hover_text("Computing size"),
+                        };
+                    });
+                });
+                row.col(|ui| {
+                    ui.vertical_centered_justified(|ui| {
+                        ui.add_space(VERTICAL_SPACING);
+                        let date = editor.install_date.map(format_date);
+                        ui.label(egui::RichText::new(date.unwrap_or_default()).small());
+                    });
+                });
+                row.col(|ui| {
+                    ui.vertical_centered_justified(|ui| {
+                        ui.add_space(VERTICAL_SPACING);
+                        let response = ui
+                            .add(
+                                egui::Label::new(
+                                    egui::RichText::new(editor.platforms.join(",")).small(),
+                                )
+                                .sense(egui::Sense::click()),
+                            )
+                            .on_hover_text("Show modules");
+                        if response.clicked() {
+                            show_modules = Some(editor.exe_path.clone());
+                        }
+                    });
+                });
+                row.col(|ui| {
+                    ui.with_layout(
+                        Layout::top_down_justified(eframe::emath::Align::Max),
+                        |ui| {
+                            ui.add_space(VERTICAL_SPACING);
+                            let version_response = ui.add(
+                                egui::Label::new(&editor.base_path).sense(egui::Sense::click()),
+                            );
+                            version_response.context_menu(|ui| {
+                                let text = egui::RichText::new("🗁 Open directory");
+                                if ui.button(text).clicked() {
+                                    open_in_file_manager(&editor.base_path);
+                                    ui.close();
+                                }
+                                let text = egui::RichText::new("🗑 Uninstall");
+                                if ui.button(text).clicked() {
+                                    uninstall = Some(editor.clone());
+                                    ui.close();
+                                }
+                            });
+                        },
+                    );
+                });
+            });
+        });
+        if show_modules.is_some() {
+            self.modules_window = show_modules;
+        }
+        self.editor_sort = sort;
+        if let Some(editor) = uninstall {
+            self.uninstall_editor(editor, ctx);
+        }
+
+        ui.add_space(VERTICAL_SPACING * 2.0);
+        self.draw_available_editors(ctx, ui);
+    }
+
+    fn draw_modules_window(&mut self, ctx: &egui::Context) {
+        let Some(exe_path) = &self.modules_window else {
+            return;
+        };
+        let editor = self
+            .hub
+            .config
+            .editors_configurations
+            .iter()
+            .find(|editor| &editor.exe_path == exe_path);
+        let Some(editor) = editor else {
+            self.modules_window = None;
+            return;
+        };
+
+        // Editors not installed by Unity Hub get the module list from the release catalog.
+        let from_catalog = editor.modules.is_empty();
+        let mut modules = editor.modules.clone();
+        if from_catalog {
+            let download = ReleaseCatalog::find(&self.releases, &editor.version)
+                .and_then(|release| release.download_for(editor.layout, editor.architecture));
+            if let Some(download) = download {
+                modules = download
+                    .modules
+                    .iter()
+                    .flat_map(|module| module.to_editor_modules(None))
+                    .collect();
+                module_installer::detect_installed(editor, &mut modules);
+            }
+        }
+
+        let mut open = true;
+        let mut install = None;
+        let installing = self.install_task.is_some();
+        egui::Window::new(format!("Modules of {}", editor.version))
+            .open(&mut open)
+            .collapsible(false)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                if modules.is_empty() {
+                    ui.label(
+                        "This editor has no modules.json file and its version is not in the release catalog.",
+                    );
+                    return;
+                }
+                if from_catalog {
+                    ui.label(egui::RichText::new("Modules listed in the release catalog").weak());
+                    ui.add_space(VERTICAL_SPACING);
+                }
+                let mut categories: Vec<&str> = modules
+                    .iter()
+                    .filter(|module| module.parent.is_none())
+                    .map(|module| module.category.as_str())
+                    .collect();
+                categories.sort();
+                categories.dedup();
+
+                for category in categories {
+                    ui.label(egui::RichText::new(category).strong());
+                    egui::Grid::new(("modules", category))
+                        .num_columns(5)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            let top_level = modules.iter().filter(|module| {
+                                module.parent.is_none() && module.category == category
+                            });
+                            for module in top_level {
+                                if draw_module_row(ui, module, 0, !installing) {
+                                    install = Some(module.id.clone());
+                                }
+                                for sub_module in module.sub_modules(&modules) {
+                                    if draw_module_row(ui, sub_module, 1, !installing) {
+                                        install = Some(sub_module.id.clone());
+                                    }
+                                }
+                            }
+                        });
+                    ui.add_space(VERTICAL_SPACING);
                 }
             });
+        if let Some(id) = install {
+            let editor = editor.clone();
+            self.start_module_install(editor, &modules, &id, ctx);
         }
-        ui.add_space(VERTICAL_SPACING * 2.0);
+        if !open {
+            self.modules_window = None;
+        }
+    }
 
-        ui.label(egui::RichText::new("Installed editor versions").heading());
+    fn draw_project(&mut self, _ctx: &egui::Context, ui: &mut Ui) {
+        egui::CollapsingHeader::new("Project roots")
+            .default_open(self.hub.config.project_roots.is_empty())
+            .show(ui, |ui| self.draw_project_roots(ui));
+        let mut changed = false;
+        egui::CollapsingHeader::new("Project scan options").show(ui, |ui| {
+            changed = draw_scan_options(ui, &mut self.hub.config.project_scan);
+        });
+        if changed {
+            self.save_config(false);
+        }
         ui.add_space(VERTICAL_SPACING);
 
-        let table2 = TableBuilder::new(ui)
-            .striped(true)
-            .vscroll(false)
-            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-            .column(Column::initial(100.0).at_least(100.0).at_most(120.0))
-            .column(Column::initial(150.0).at_least(150.0).at_most(400.0))
-            .column(Column::remainder().at_least(260.0))
-            .resizable(false);
-
-        table2.body(|body| {
-            body.rows(
-                text_height,
-                self.hub.config.editors_configurations.len(),
-                |mut row| {
-                    let row_index = row.index();
-                    let editor = &self.hub.config.editors_configurations[row_index];
-                    row.col(|ui| {
-                        ui.vertical_centered_justified(|ui| {
-                            ui.add_space(VERTICAL_SPACING);
-                            ui.label(&editor.version);
-                        });
-                    });
-                    row.col(|ui| {
-                        ui.vertical_centered_justified(|ui| {
-                            ui.add_space(VERTICAL_SPACING);
-                            ui.label(egui::RichText::new(editor.platforms.join(",")).small());
-                        });
-                    });
-                    row.col(|ui| {
-                        ui.with_layout(
-                            Layout::top_down_justified(eframe::emath::Align::Max),
-                            |ui| {
-                                ui.add_space(VERTICAL_SPACING);
-                                let version_response = ui.add(
-                                    egui::Label::new(&editor.base_path).sense(egui::Sense::click()),
-                                );
-                                version_response.context_menu(|ui| {
-                                    let text = egui::RichText::new("🗁 Open directory");
-                                    if ui.button(text).clicked() {
-                                        use std::process::Command;
-                                        Command::new(FILE_MANAGER)
-                                            .arg(&editor.base_path)
-                                            .spawn()
-                                            .unwrap();
-                                        ui.close();
-                                    }
-                                });
-                            },
-                        );
-                    });
-                },
+        let hidden = self.hub.projects.iter().filter(|p| p.hidden).count();
+        if hidden > 0 {
+            ui.checkbox(
+                &mut self.show_hidden_projects,
+                format!("Show hidden projects ({})", hidden),
             );
-        });
-    }
-    fn draw_project(&mut self, _ctx: &egui::Context, ui: &mut Ui) {
+        }
+
         let text_height = egui::TextStyle::Body.resolve(ui.style()).size * tweak!(3.0);
 
         let projects = self.hub.projects.clone();
-        for (i, project) in projects.iter().enumerate() {
+        let mut action = None;
+        let mut row = 0;
+        for project in projects.iter() {
+            if project.hidden && !self.show_hidden_projects {
+                continue;
+            }
+            row += 1;
             let editor_for_project_exists = self.hub.editor_for_project(project).is_some();
+            let running = self.editors.get(&project.path).is_some();
             ui.horizontal(|ui| {
-                let color = if i % 2 == 0 {
+                let color = if row % 2 == 1 {
                     Color32::from_rgba_premultiplied(0, 0, 0, 30)
                 } else {
                     egui::Color32::TRANSPARENT
@@ -238,28 +1570,101 @@ impl HubClient {
                                 );
                             }
                             for editor in &self.hub.config.editors_configurations {
-                                let mut text = egui::RichText::new(editor.version.to_string());
-                                if editor.version.contains(&project.version) {
+                                let native =
+                                    editor.architecture.runs_natively_on(self.host_architecture);
+                                let mut text = egui::RichText::new(if native {
+                                    editor.version.to_string()
+                                } else {
+                                    format!("{} ⚠ {}", editor.version, editor.architecture.name())
+                                });
+                                if editor.version == project.version {
                                     text = text.strong().color(Color32::GREEN);
                                 }
-                                if ui.button(text).clicked() {
-                                    Hub::run_project(editor, project);
+                                let button = ui
+                                    .add_enabled(!running, egui::Button::new(text))
+                                    .on_disabled_hover_text("The project is already open");
+                                if button.clicked() {
+                                    if self.confirm_architecture(editor) {
+                                        action = Some(ProjectAction::Open(Box::new((
+                                            editor.clone(),
+                                            project.clone(),
+                                        ))));
+                                    }
                                     ui.close();
                                 }
                             }
                         });
 
                         if ui.button("Open directory").clicked() {
-                            use std::process::Command;
-                            Command::new(FILE_MANAGER)
-                                .arg(&project.path)
-                                .spawn()
-                                .unwrap();
+                            open_in_file_manager(&project.path);
+                            ui.close();
+                        }
+                        if ui.button("Run in batch mode…").clicked() {
+                            self.jobs.open_batch_dialog(&project.path);
+                            ui.close();
+                        }
+                        ui.add_enabled_ui(editor_for_project_exists, |ui| {
+                            ui.menu_button("Run tests", |ui| {
+                                for platform in TestPlatform::ALL {
+                                    if ui.button(platform.name()).clicked() {
+                                        action = Some(ProjectAction::RunTests(
+                                            project.clone(),
+                                            platform,
+                                        ));
+                                        ui.close();
+                                    }
+                                }
+                            });
+                        });
+                        if ui.button("Show logs").clicked() {
+                            let path = Path::new(&project.path);
+                            let log = editor_log::project_logs(path)
+                                .into_iter()
+                                .next()
+                                .unwrap_or_else(editor_log::editor_log_path);
+                            self.logs.open(&log, Some(path.to_path_buf()));
+                            self.current_tab = WindowTab::Logs;
+                            ui.close();
+                        }
+                        if ui.button("Build profiles…").clicked() {
+                            self.builds.open(&project.path);
+                            ui.close();
+                        }
+                        if ui.button("Locate…").clicked() {
+                            action = Some(ProjectAction::Locate(project.clone()));
+                            ui.close();
+                        }
+                        ui.separator();
+                        let hide_text = if project.hidden { "Unhide" } else { "Hide" };
+                        if ui.button(hide_text).clicked() {
+                            action = Some(ProjectAction::SetHidden(
+                                project.path.clone(),
+                                !project.hidden,
+                            ));
+                            ui.close();
+                        }
+                        if ui
+                            .button("🚮 Remove from list")
+                            .on_hover_text(
+                                "Scans and the Unity registry won't add the project again",
+                            )
+                            .clicked()
+                        {
+                            action = Some(ProjectAction::Remove(project.path.clone()));
                             ui.close();
                         }
                     });
-                    ui.label(egui::RichText::new(project.title.to_string()).heading())
-                        .on_hover_text(&project.path);
+                    let mut title = egui::RichText::new(project.title.to_string()).heading();
+                    if !project.is_valid {
+                        title = title.strikethrough().weak();
+                    } else if project.hidden {
+                        title = title.weak();
+                    }
+                    ui.label(title).on_hover_text(&project.path);
+                    if !project.is_valid {
+                        ui.label(egui::RichText::new("missing").small().color(Color32::RED))
+                            .on_hover_text(format!("{} no longer exists", &project.path));
+                    }
 
                     if !project.branch.is_empty() {
                         ui.add_space(TOP_SIDE_MARGIN);
@@ -283,6 +1688,38 @@ impl HubClient {
                         ui.available_width() - btn_width - TOP_SIDE_MARGIN,
                         text_height,
                     ));
+                    if !project.is_valid {
+                        if ui
+                            .add_sized([btn_width, text_height], egui::Button::new("Locate…"))
+                            .on_hover_text("Find the project after it was moved")
+                            .clicked()
+                        {
+                            action = Some(ProjectAction::Locate(project.clone()));
+                        }
+                        return;
+                    }
+                    if let Some(running) = self.editors.get(&project.path) {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "● {}",
+                                format_duration(running.running_for())
+                            ))
+                            .color(Color32::GREEN),
+                        )
+                        .on_hover_text(format!(
+                            "Running in {} (pid {})",
+                            running.editor_version,
+                            running.pid()
+                        ));
+                        if ui
+                            .add_sized([btn_width, text_height], egui::Button::new("Kill"))
+                            .on_hover_text("Kill the editor, unsaved changes are lost")
+                            .clicked()
+                        {
+                            action = Some(ProjectAction::Kill(project.path.clone()));
+                        }
+                        return;
+                    }
                     let text = if editor_for_project_exists {
                         egui::RichText::new("Open".to_string())
                     } else {
@@ -298,19 +1735,447 @@ impl HubClient {
                             &project.version
                         ));
                     } else if added_button.clicked() {
-                        self.hub.run_project_nr(i);
+                        if let Some(editor) = self
+                            .hub
+                            .editor_for_project(project)
+                            .filter(|editor| self.confirm_architecture(editor))
+                        {
+                            action = Some(ProjectAction::Open(Box::new((editor, project.clone()))));
+                        }
                     }
                 });
             });
         }
+
+        match action {
+            Some(ProjectAction::Open(open)) => {
+                let (editor, project) = *open;
+                if let Err(err) = self.editors.launch(&editor, &project) {
+                    rfd::MessageDialog::new()
+                        .set_title("Failed to open the project")
+                        .set_description(format!("{}: {}", editor.exe_path, err))
+                        .set_level(rfd::MessageLevel::Error)
+                        .show();
+                }
+            }
+            Some(ProjectAction::Kill(path)) => {
+                let confirmed = rfd::MessageDialog::new()
+                    .set_title("Kill editor")
+                    .set_description("Unsaved changes in the editor will be lost.")
+                    .set_buttons(rfd::MessageButtons::YesNo)
+                    .show();
+                if confirmed == rfd::MessageDialogResult::Yes {
+                    if let Err(err) = self.editors.kill(&path) {
+                        rfd::MessageDialog::new()
+                            .set_title("Failed to kill the editor")
+                            .set_description(err.to_string())
+                            .set_level(rfd::MessageLevel::Error)
+                            .show();
+                    }
+                }
+            }
+            Some(ProjectAction::SetHidden(path, hidden)) => {
+                self.hub.set_project_hidden(&path, hidden);
+                self.save_config(false);
+            }
+            Some(ProjectAction::Remove(path)) => {
+                self.hub.remove_project(&path);
+                self.save_config(false);
+            }
+            Some(ProjectAction::Locate(project)) => self.locate_project(&project),
+            Some(ProjectAction::RunTests(project, platform)) => {
+                let queued =
+                    tests_panel::queue_tests(&self.hub, &mut self.jobs, &project, platform);
+                if queued {
+                    self.current_tab = WindowTab::Jobs;
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Collects editors that exited, keeping the crashed ones around until dismissed.
+    fn receive_editor_exits(&mut self) {
+        if self.editors.is_empty() {
+            return;
+        }
+        self.editor_crashes
+            .extend(self.editors.poll().into_iter().filter(EditorExit::crashed));
+        // Keeps the running time of the project rows and the exit checks going.
+        self.ctx.request_repaint_after(Duration::from_secs(1));
+    }
+
+    fn draw_editor_crashes(&mut self, ctx: &egui::Context) {
+        let mut dismissed = None;
+        let mut show_log = None;
+        for (i, exit) in self.editor_crashes.iter().enumerate() {
+            egui::Window::new(format!("{} stopped", exit.title))
+                .id(egui::Id::new(("editor_crash", i)))
+                .default_width(560.0)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "Unity {} stopped with {} after {}",
+                            exit.editor_version,
+                            exit.status(),
+                            format_duration(exit.duration)
+                        ))
+                        .color(Color32::RED),
+                    );
+                    ui.add_space(VERTICAL_SPACING);
+                    if exit.log_tail.is_empty() {
+                        ui.label(egui::RichText::new("The editor log is empty.").weak());
+                    } else {
+                        egui::ScrollArea::vertical()
+                            .max_height(240.0)
+                            .stick_to_bottom(true)
+                            .show(ui, |ui| {
+                                for line in &exit.log_tail {
+                                    ui.label(egui::RichText::new(line).monospace());
+                                }
+                            });
+                    }
+                    for report in &exit.crash_reports {
+                        ui.horizontal(|ui| {
+                            ui.label("Crash report");
+                            if ui.link(report.to_string_lossy()).clicked() {
+                                open_in_file_manager(&report.to_string_lossy());
+                            }
+                        });
+                    }
+                    ui.add_space(VERTICAL_SPACING);
+                    ui.horizontal(|ui| {
+                        if ui.button("Show log").clicked() {
+                            show_log = Some(i);
+                        }
+                        if ui.button("Dismiss").clicked() {
+                            dismissed = Some(i);
+                        }
+                    });
+                });
+        }
+        if let Some(i) = show_log {
+            let exit = self.editor_crashes.remove(i);
+            let project = PathBuf::from(&exit.project_path);
+            self.logs.open(&exit.log_file, Some(project));
+            self.current_tab = WindowTab::Logs;
+        } else if let Some(i) = dismissed {
+            self.editor_crashes.remove(i);
+        }
+    }
+
+    /// Asks for the new location of a moved project, either the project folder itself or a
+    /// folder to look for it in, and relinks it keeping its metadata.
+    fn locate_project(&mut self, project: &UnityProject) {
+        let Some(dir) = FileDialog::new()
+            .set_title(format!("Locate {}", project.title))
+            .pick_folder()
+        else {
+            return;
+        };
+        let dir = dir.to_string_lossy().to_string();
+        if UnityProject::try_get_project_at_path(&dir).is_some() {
+            self.relink_project(project, &dir);
+            return;
+        }
+
+        if let Some(locate) = self.project_locate.take() {
+            locate.cancel.cancel();
+        }
+        let cancel = CancelToken::default();
+        let (sender, receiver) = mpsc::channel();
+        let ctx = self.ctx.clone();
+        let cancelled = cancel.clone();
+        let options = self.hub.config.project_scan.clone();
+        let (moved, root) = (project.clone(), PathBuf::from(&dir));
+        std::thread::spawn(move || {
+            let candidates = Hub::find_moved_project(&moved, &root, &options, &cancelled);
+            if !cancelled.is_cancelled() {
+                let _ = sender.send(candidates);
+                ctx.request_repaint();
+            }
+        });
+        self.project_locate = Some(ProjectLocate {
+            project: project.clone(),
+            dir,
+            cancel,
+            result: receiver,
+        });
+    }
+
+    /// Asks to relink the best match once the search of `locate_project` is done.
+    fn receive_project_locate(&mut self) {
+        let Some(locate) = &self.project_locate else {
+            return;
+        };
+        let Ok(candidates) = locate.result.try_recv() else {
+            return;
+        };
+        let Some(locate) = self.project_locate.take() else {
+            return;
+        };
+        let Some(best) = candidates.first() else {
+            rfd::MessageDialog::new()
+                .set_title("Project not found")
+                .set_description(format!(
+                    "No project with the same folder name, product name or git remote as {} was found in {}.",
+                    locate.project.title, locate.dir
+                ))
+                .set_level(rfd::MessageLevel::Warning)
+                .show();
+            return;
+        };
+        let confirmed = rfd::MessageDialog::new()
+            .set_title("Relink project")
+            .set_description(format!(
+                "Use {} as the new location of {}?",
+                best.path, locate.project.title
+            ))
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .show();
+        if confirmed == rfd::MessageDialogResult::Yes {
+            self.relink_project(&locate.project, &best.path);
+        }
+    }
+
+    fn relink_project(&mut self, project: &UnityProject, new_path: &str) {
+        if self.hub.relink_project(&project.path, new_path) {
+            self.save_config(false);
+        }
+    }
+
+    /// Folders rescanned for projects on startup, with their last scan results.
+    fn draw_project_roots(&mut self, ui: &mut Ui) {
+        let roots = self.hub.config.project_roots.clone();
+        let mut changed = false;
+        for (i, root) in roots.iter().enumerate() {
+            let exists = root.resolved().is_dir();
+            let report = self.hub.config.project_root_reports.get(&root.path);
+            ui.horizontal(|ui| {
+                let status = if exists {
+                    egui::RichText::new("✔").color(Color32::GREEN)
+                } else {
+                    egui::RichText::new("✖").color(Color32::RED)
+                };
+                ui.label(status).on_hover_text(if exists {
+                    "Directory exists"
+                } else {
+                    "Directory does not exist"
+                });
+                let mut enabled = root.enabled;
+                if ui
+                    .checkbox(&mut enabled, "")
+                    .on_hover_text("Scan this folder")
+                    .changed()
+                {
+                    self.hub.config.project_roots[i].enabled = enabled;
+                    changed = true;
+                }
+                ui.label(root.display_name()).on_hover_text(&root.path);
+                if let Some(report) = report {
+                    ui.label(
+                        egui::RichText::new(format!("{} project(s)", report.projects)).small(),
+                    );
+                    if report.missing > 0 {
+                        ui.label(
+                            egui::RichText::new(format!("{} missing", report.missing))
+                                .small()
+                                .color(Color32::RED),
+                        );
+                    }
+                    if !report.errors.is_empty() {
+                        ui.label(
+                            egui::RichText::new(format!("⚠ {}", report.errors.len()))
+                                .small()
+                                .color(Color32::YELLOW),
+                        )
+                        .on_hover_text(report.errors.join("\n"));
+                    }
+                }
+                if ui.button("🚮 Remove").clicked() {
+                    self.hub.config.project_roots.remove(i);
+                    changed = true;
+                }
+            });
+            if changed {
+                break;
+            }
+        }
+        ui.horizontal(|ui| {
+            if ui
+                .button("🖴 Add root")
+                .on_hover_text("Add folder that is rescanned for projects on startup")
+                .clicked()
+            {
+                if let Some(dir) = FileDialog::new().pick_folder() {
+                    let path = paths::contract(&dir.to_string_lossy());
+                    if !self.hub.config.project_roots.iter().any(|r| r.path == path) {
+                        self.hub.config.project_roots.push(ProjectRoot::new(&path));
+                        changed = true;
+                    }
+                }
+            }
+            if ui
+                .add_enabled(self.update_task.is_none(), egui::Button::new("⟳ Rescan"))
+                .on_hover_text("Look for new and missing projects in all roots")
+                .clicked()
+            {
+                self.start_update(false);
+            }
+        });
+        if changed {
+            self.save_config(true);
+        }
+    }
+
+    fn draw_discovery(&mut self, ui: &mut Ui) {
+        ui.label(egui::RichText::new("Automatic discovery").heading());
+        ui.add_space(VERTICAL_SPACING);
+
+        let mut changed = false;
+        changed |= ui
+            .checkbox(
+                &mut self.hub.config.auto_discovery,
+                "Look for editors outside of search paths",
+            )
+            .on_hover_text("Unity Hub install locations, PATH, environment variables and well-known directories")
+            .changed();
+        for (source, report) in &self.hub.config.discovery_reports {
+            ui.horizontal(|ui| {
+                ui.label(source.name());
+                ui.label(egui::RichText::new(format!("{} editor(s)", report.editors)).small());
+                if !report.errors.is_empty() {
+                    ui.label(
+                        egui::RichText::new(format!("⚠ {}", report.errors.len()))
+                            .small()
+                            .color(Color32::YELLOW),
+                    )
+                    .on_hover_text(report.errors.join("\n"));
+                }
+            });
+        }
+
+        if !self.hub.config.editor_executables.is_empty() {
+            ui.add_space(VERTICAL_SPACING);
+            ui.label(egui::RichText::new("Registered editor executables").strong());
+        }
+        let executables = self.hub.config.editor_executables.clone();
+        for (i, exe) in executables.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(exe);
+                let height = tweak!(30.0);
+                let button_width = tweak!(100.0);
+                ui.allocate_space(egui::vec2(
+                    ui.available_width() - button_width - TOP_SIDE_MARGIN,
+                    height,
+                ));
+                if ui
+                    .add_sized([button_width, height], egui::Button::new("🚮 Remove"))
+                    .clicked()
+                {
+                    self.hub.config.editor_executables.remove(i);
+                    changed = true;
+                }
+            });
+            if changed {
+                break;
+            }
+        }
+        if changed {
+            self.save_config(true);
+        }
+    }
+
+    /// Asks before launching an editor that would run under emulation or not at all.
+    fn confirm_architecture(&self, editor: &UnityEditor) -> bool {
+        if editor.architecture.runs_natively_on(self.host_architecture) {
+            return true;
+        }
+        let result = rfd::MessageDialog::new()
+            .set_title("Editor architecture mismatch")
+            .set_description(format!(
+                "Unity {} is a {} build and will not run natively on this {} machine. Open anyway?",
+                editor.version,
+                editor.architecture.name(),
+                self.host_architecture.name()
+            ))
+            .set_level(rfd::MessageLevel::Warning)
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .show();
+        result == rfd::MessageDialogResult::Yes
     }
 
     fn draw_editors_header(&mut self, _ctx: &egui::Context, ui: &mut Ui) {
         add_header(ui);
 
-        let available_width =
-            ui.available_width() - TOP_BUTTON_WIDTH - TOP_SIDE_MARGIN - TOP_SIDE_MARGIN;
+        let available_width = ui.available_width() - TOP_BUTTON_WIDTH * 3.0 - TOP_SIDE_MARGIN * 4.0;
         ui.allocate_space(egui::vec2(available_width, HEADER_HEIGHT));
+        if ui
+            .add_sized([TOP_BUTTON_WIDTH, 30.0], egui::Button::new("➕ Add editor"))
+            .on_hover_text("Register a single editor executable")
+            .clicked()
+        {
+            let file = FileDialog::new().pick_file();
+            if let Some(file) = file {
+                match discovery::editor_dir_from_exe(&file) {
+                    Some(_) => {
+                        self.hub
+                            .config
+                            .editor_executables
+                            .push(paths::contract(&file.to_string_lossy()));
+                        self.save_config(true);
+                    }
+                    None => {
+                        rfd::MessageDialog::new()
+                            .set_title("Not a Unity editor")
+                            .set_description(format!(
+                                "{} is not a Unity editor executable.",
+                                file.to_string_lossy()
+                            ))
+                            .set_level(rfd::MessageLevel::Warning)
+                            .show();
+                    }
+                }
+            }
+        }
+        ui.allocate_space(egui::vec2(TOP_SIDE_MARGIN, 10.0));
+        if ui
+            .add_sized(
+                [TOP_BUTTON_WIDTH, 30.0],
+                egui::Button::new("📦 Install archive"),
+            )
+            .on_hover_text("Install an editor from a downloaded archive or installer")
+            .clicked()
+        {
+            let file = FileDialog::new()
+                .add_filter("Editor archive", &["xz", "pkg", "dmg", "exe"])
+                .pick_file();
+            if let Some(file) = file {
+                if ArchiveKind::from_path(&file).is_some_and(|kind| kind.supported_on_host()) {
+                    self.install_dialog = Some(InstallDialog {
+                        title: file
+                            .file_name()
+                            .map(|name| name.to_string_lossy().to_string())
+                            .unwrap_or_default(),
+                        source: ArchiveSource::File(file),
+                        version: None,
+                        checksum: String::new(),
+                        destination: 0,
+                    });
+                } else {
+                    rfd::MessageDialog::new()
+                        .set_title("Unsupported archive")
+                        .set_description(format!(
+                            "{} can't be installed on this system.",
+                            file.to_string_lossy()
+                        ))
+                        .set_level(rfd::MessageLevel::Warning)
+                        .show();
+                }
+            }
+        }
+        ui.allocate_space(egui::vec2(TOP_SIDE_MARGIN, 10.0));
         if ui
             .add_sized(
                 [TOP_BUTTON_WIDTH, 30.0],
@@ -324,7 +2189,7 @@ impl HubClient {
                 self.hub
                     .config
                     .unity_search_paths
-                    .push(dir.into_os_string().into_string().unwrap());
+                    .push(paths::contract(&dir.to_string_lossy()).as_str().into());
                 self.save_config(true);
             }
         }
@@ -334,9 +2199,19 @@ impl HubClient {
     fn draw_project_header(&mut self, _ctx: &egui::Context, ui: &mut Ui) {
         add_header(ui);
 
-        let available_width =
-            ui.available_width() - TOP_BUTTON_WIDTH - TOP_SIDE_MARGIN - TOP_SIDE_MARGIN;
+        let available_width = ui.available_width() - TOP_BUTTON_WIDTH * 2.0 - TOP_SIDE_MARGIN * 3.0;
         ui.allocate_space(egui::vec2(available_width, HEADER_HEIGHT));
+        if ui
+            .add_sized(
+                [TOP_BUTTON_WIDTH, 30.0],
+                egui::Button::new("⟳ Force rescan"),
+            )
+            .on_hover_text("Read all projects and editors again, ignoring cached information")
+            .clicked()
+        {
+            self.start_update(true);
+        }
+        ui.allocate_space(egui::vec2(TOP_SIDE_MARGIN, 10.0));
         if ui
             .add_sized(
                 [TOP_BUTTON_WIDTH, 30.0],
@@ -348,20 +2223,7 @@ impl HubClient {
             let directory = FileDialog::new().pick_folder();
 
             if let Some(dir) = directory {
-                let amount = self.hub.search_for_projects_at_path(&dir);
-                let mut message = rfd::MessageDialog::new().set_title("Search ended");
-
-                match amount {
-                    0 => {
-                        message = message
-                            .set_description("No new projects found.")
-                            .set_level(rfd::MessageLevel::Warning)
-                    }
-                    1 => message = message.set_description("Project founded!"),
-                    _ => message = message.set_description(format!("Founded {} projects.", amount)),
-                }
-                message.show();
-                self.save_config(true);
+                self.start_project_scan(dir, false);
             }
         }
         ui.allocate_space(egui::vec2(TOP_SIDE_MARGIN, 10.0));
@@ -400,11 +2262,259 @@ impl HubClient {
             if self.tab_button(ui, &WindowTab::Editors, tweak!("🛠 Editors")) {
                 self.current_tab = WindowTab::Editors;
             }
+            let running = self.jobs.unfinished();
+            let jobs_title = if running > 0 {
+                format!("⚙ Jobs ({})", running)
+            } else {
+                "⚙ Jobs".to_string()
+            };
+            if self.tab_button(ui, &WindowTab::Jobs, &jobs_title) {
+                self.current_tab = WindowTab::Jobs;
+            }
+            if self.tab_button(ui, &WindowTab::Logs, tweak!("📜 Logs")) {
+                self.current_tab = WindowTab::Logs;
+            }
+            let account_title = if self.account.has_warning() {
+                "🔑 Account ⚠"
+            } else {
+                "🔑 Account"
+            };
+            if self.tab_button(ui, &WindowTab::Account, account_title) {
+                self.current_tab = WindowTab::Account;
+            }
+        });
+    }
+}
+
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+/// Returns true when installing the module was requested.
+fn draw_module_row(ui: &mut Ui, module: &EditorModule, indent: usize, can_install: bool) -> bool {
+    let status = if module.installed() {
+        egui::RichText::new("✔").color(Color32::GREEN)
+    } else {
+        egui::RichText::new("✖").weak()
+    };
+    ui.label(status).on_hover_text(if module.installed() {
+        "Installed"
+    } else {
+        "Not installed"
+    });
+    ui.label(format!("{}{}", "    ".repeat(indent), module.name))
+        .on_hover_text(&module.description);
+    ui.label(egui::RichText::new(format_size(module.download_size)).small())
+        .on_hover_text("Download size");
+    ui.label(egui::RichText::new(format_size(module.installed_size)).small())
+        .on_hover_text("Installed size");
+    let install = if !module.installed() && module.download_url.is_some() {
+        ui.add_enabled(can_install, egui::Button::new("⬇ Install").small())
+            .clicked()
+    } else {
+        ui.label("");
+        false
+    };
+    ui.end_row();
+    install
+}
+
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Catalog client using the endpoint from the configuration, cached next to the config file.
+fn release_catalog(hub: &Hub) -> ReleaseCatalog {
+    let cache_path = confy::get_configuration_file_path("rusty_hub_egui", "config")
+        .ok()
+        .and_then(|path| path.parent().map(|dir| dir.join("releases.json")));
+    ReleaseCatalog::new(&hub.config.release_catalog_url, cache_path)
+}
+
+/// Date as `YYYY-MM-DD` in UTC.
+fn format_date(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    // Civil date from days since epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Clickable column title, clicking the sorted column again flips the order.
+fn sort_header(ui: &mut Ui, sort: &mut EditorSort, column: EditorColumn, title: &str) {
+    let title = if sort.column == column {
+        format!("{} {}", title, if sort.ascending { "⏶" } else { "⏷" })
+    } else {
+        title.to_string()
+    };
+    let response =
+        ui.add(egui::Label::new(egui::RichText::new(title).strong()).sense(egui::Sense::click()));
+    if response.clicked() {
+        if sort.column == column {
+            sort.ascending = !sort.ascending;
+        } else {
+            sort.column = column;
+            sort.ascending = true;
+        }
+    }
+}
+
+/// Draws editable settings of a single search path, returns true when any of them changed.
+fn draw_search_path_options(ui: &mut Ui, index: usize, search_path: &mut SearchPath) -> bool {
+    let mut changed = false;
+    egui::Grid::new(("search_path_grid", index))
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Label");
+            changed |= text_setting(ui, ("label", index), &mut search_path.label);
+            ui.end_row();
+
+            ui.label("Depth");
+            changed |= ui
+                .add(egui::DragValue::new(&mut search_path.depth).range(1..=10))
+                .changed();
+            ui.end_row();
+
+            ui.label("Include");
+            let mut include = search_path.include.join(", ");
+            if text_setting(ui, ("include", index), &mut include) {
+                search_path.include = split_patterns(&include);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("Exclude");
+            let mut exclude = search_path.exclude.join(", ");
+            if text_setting(ui, ("exclude", index), &mut exclude) {
+                search_path.exclude = split_patterns(&exclude);
+                changed = true;
+            }
+            ui.end_row();
+        });
+    changed
+}
+
+/// Draws settings used when looking for projects, returns true when any of them changed.
+fn draw_scan_options(ui: &mut Ui, options: &mut ScanOptions) -> bool {
+    let mut changed = false;
+    egui::Grid::new("scan_options_grid")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Depth");
+            changed |= ui
+                .add(egui::DragValue::new(&mut options.max_depth).range(1..=12))
+                .changed();
+            ui.end_row();
+
+            ui.label("Skip folders");
+            let mut skip_dirs = options.skip_dirs.join(", ");
+            if text_setting(ui, "skip_dirs", &mut skip_dirs) {
+                options.skip_dirs = split_patterns(&skip_dirs);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("Ignore");
+            let mut ignore = options.ignore.join(", ");
+            if text_setting(ui, "scan_ignore", &mut ignore) {
+                options.ignore = split_patterns(&ignore);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("");
+            changed |= ui
+                .checkbox(&mut options.respect_gitignore, "Respect .gitignore")
+                .changed();
+            ui.end_row();
+
+            ui.label("");
+            changed |= ui
+                .checkbox(&mut options.follow_symlinks, "Follow symlinks")
+                .changed();
+            ui.end_row();
         });
+    let errors = options.pattern_errors();
+    if !errors.is_empty() {
+        ui.label(egui::RichText::new(errors.join("\n")).color(Color32::YELLOW));
+    }
+    if ui.button("Reset to defaults").clicked() {
+        *options = ScanOptions::default();
+        changed = true;
+    }
+    changed
+}
+
+/// Single line text field that reports a change only once editing is finished.
+pub(crate) fn text_setting(ui: &mut Ui, id: impl std::hash::Hash, value: &mut String) -> bool {
+    let id = ui.make_persistent_id(id);
+    let mut buffer = ui
+        .data_mut(|d| d.get_temp::<String>(id))
+        .unwrap_or_else(|| value.clone());
+    let response = ui.text_edit_singleline(&mut buffer);
+    if response.lost_focus() {
+        ui.data_mut(|d| d.remove::<String>(id));
+        if &buffer != value {
+            *value = buffer;
+            return true;
+        }
+    } else if response.has_focus() {
+        ui.data_mut(|d| d.insert_temp(id, buffer));
+    }
+    false
+}
+
+pub(crate) fn split_patterns(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+pub(crate) fn open_in_file_manager(path: &str) {
+    use std::process::Command;
+    if let Err(err) = process::spawn_detached(Command::new(FILE_MANAGER).arg(path)) {
+        rfd::MessageDialog::new()
+            .set_title("Failed to open the file manager")
+            .set_description(format!("{}: {}", FILE_MANAGER, err))
+            .set_level(rfd::MessageLevel::Error)
+            .show();
     }
 }
 
-fn add_header(ui: &mut Ui) {
+pub(crate) fn add_header(ui: &mut Ui) {
     let text = egui::RichText::new(APP_NAME)
         .font(FontId::new(26.0, FontFamily::Name("semibold".into())))
         .strong();
@@ -424,6 +2534,8 @@ impl eframe::App for HubClient {
                         match self.current_tab {
                             WindowTab::Projects => self.draw_project_header(ctx, ui),
                             WindowTab::Editors => self.draw_editors_header(ctx, ui),
+                            WindowTab::Jobs => self.jobs.draw_header(ui),
+                            WindowTab::Logs | WindowTab::Account => add_header(ui),
                         };
                     },
                 );
@@ -442,8 +2554,35 @@ impl eframe::App for HubClient {
                     format!("{} v {}", egui::special_emojis::GITHUB, VERSION),
                     HOMEPAGE,
                 );
+                ui.with_layout(Layout::left_to_right(eframe::emath::Align::Center), |ui| {
+                    self.draw_scan_status(ui);
+                });
             });
         });
+        self.receive_update();
+        self.receive_project_scan();
+        self.receive_project_locate();
+        self.receive_watch_events();
+        self.jobs.receive_events();
+        self.receive_editor_exits();
+        self.receive_editor_sizes();
+        self.receive_releases();
+        self.compute_editor_sizes(ctx);
         self.draw_central_panel(ctx);
+        self.draw_modules_window(ctx);
+        self.draw_uninstall_window(ctx);
+        self.draw_install_dialog(ctx);
+        self.draw_install_window(ctx);
+        if self.jobs.draw_batch_dialog(ctx, &self.hub) {
+            self.current_tab = WindowTab::Jobs;
+        }
+        let builds = self.builds.draw(ctx, &mut self.hub, &mut self.jobs);
+        if builds.changed {
+            self.save_config(false);
+        }
+        if builds.queued {
+            self.current_tab = WindowTab::Jobs;
+        }
+        self.draw_editor_crashes(ctx);
     }
 }
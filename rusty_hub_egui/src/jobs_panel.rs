@@ -0,0 +1,421 @@
+use crate::{
+    consts::{HEADER_HEIGHT, TOP_BUTTON_WIDTH, TOP_SIDE_MARGIN, VERTICAL_SPACING},
+    hub_client::{add_header, format_duration, format_size, open_in_file_manager},
+    tests_panel::draw_test_run,
+};
+use eframe::{
+    egui::{self, Ui},
+    epaint::Color32,
+};
+use egui_extras::{Column, TableBuilder};
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant, SystemTime};
+use unity_hub_lib::{
+    batch::{BatchJob, JobError, JobEvent, JobId, JobOutcome, JobQueue},
+    build_profile::{self, Artifact},
+    hub::Hub,
+    test_runner::{self, TestRun},
+};
+
+/// Lines of a job log kept in memory, the whole log stays in the log file.
+const MAX_JOB_LOG_LINES: usize = 2000;
+
+enum JobState {
+    Queued,
+    Running(Instant),
+    Finished(Result<JobOutcome, JobError>),
+}
+
+struct JobView {
+    id: JobId,
+    job: BatchJob,
+    state: JobState,
+    /// Tail of the log file, at most `MAX_JOB_LOG_LINES`.
+    log: VecDeque<String>,
+    /// Output path and queue time of a build, artifacts are collected from there.
+    build: Option<(PathBuf, SystemTime)>,
+    artifacts: Vec<Artifact>,
+    /// Results file of a test run, parsed once the job finishes.
+    test_results: Option<PathBuf>,
+    test_run: Option<Result<TestRun, String>>,
+}
+
+/// Batch run waiting for the user to pick the method and projects.
+struct BatchDialog {
+    method: String,
+    args: String,
+    /// Paths of the selected projects.
+    projects: HashSet<String>,
+}
+
+/// Batch jobs of this session shown by the Jobs tab.
+pub struct JobsPanel {
+    queue: JobQueue,
+    events: Receiver<JobEvent>,
+    /// Batch jobs of this session, oldest first.
+    jobs: Vec<JobView>,
+    selected: Option<JobId>,
+    batch_dialog: Option<BatchDialog>,
+}
+
+impl JobsPanel {
+    pub fn new(ctx: &egui::Context) -> Self {
+        let (sender, events) = mpsc::channel();
+        let ctx = ctx.clone();
+        let queue = JobQueue::new(move |event| {
+            let _ = sender.send(event);
+            ctx.request_repaint();
+        });
+        Self {
+            queue,
+            events,
+            jobs: Vec::new(),
+            selected: None,
+            batch_dialog: None,
+        }
+    }
+
+    /// Number of jobs that didn't finish yet.
+    pub fn unfinished(&self) -> usize {
+        self.jobs
+            .iter()
+            .filter(|view| !matches!(view.state, JobState::Finished(_)))
+            .count()
+    }
+
+    pub fn receive_events(&mut self) {
+        while let Ok(event) = self.events.try_recv() {
+            let id = match &event {
+                JobEvent::Started(id) | JobEvent::Log(id, _) | JobEvent::Finished(id, _) => *id,
+            };
+            let Some(view) = self.jobs.iter_mut().find(|view| view.id == id) else {
+                continue;
+            };
+            match event {
+                JobEvent::Started(_) => view.state = JobState::Running(Instant::now()),
+                JobEvent::Log(_, line) => {
+                    if view.log.len() == MAX_JOB_LOG_LINES {
+                        view.log.pop_front();
+                    }
+                    view.log.push_back(line);
+                }
+                JobEvent::Finished(_, result) => {
+                    if let Some((output, since)) = &view.build {
+                        view.artifacts = build_profile::collect_artifacts(output, *since);
+                    }
+                    if let Some(results) = &view.test_results {
+                        view.test_run =
+                            Some(test_runner::read_results(results).map_err(|e| e.to_string()));
+                    }
+                    view.state = JobState::Finished(result);
+                }
+            }
+        }
+    }
+
+    /// Queues `job`, with `build` its artifacts are collected from that output path.
+    pub fn queue(&mut self, job: BatchJob, build: Option<PathBuf>) -> JobId {
+        let id = self.queue.push(job.clone());
+        self.jobs.push(JobView {
+            id,
+            job,
+            state: JobState::Queued,
+            log: VecDeque::new(),
+            build: build.map(|output| (output, SystemTime::now())),
+            artifacts: Vec::new(),
+            test_results: None,
+            test_run: None,
+        });
+        self.selected.get_or_insert(id);
+        id
+    }
+
+    /// Queues a test run and selects it, `results` is read once it finishes.
+    pub fn queue_test_run(&mut self, job: BatchJob, results: PathBuf) {
+        let id = self.queue(job, None);
+        if let Some(view) = self.jobs.iter_mut().find(|view| view.id == id) {
+            view.test_results = Some(results);
+        }
+        self.selected = Some(id);
+    }
+
+    pub fn open_batch_dialog(&mut self, project: &str) {
+        self.batch_dialog = Some(BatchDialog {
+            method: String::new(),
+            args: String::new(),
+            projects: HashSet::from([project.to_string()]),
+        });
+    }
+
+    /// Asks for the method and projects of a batch run, queueing a job per project.
+    /// Returns true when jobs were queued.
+    pub fn draw_batch_dialog(&mut self, ctx: &egui::Context, hub: &Hub) -> bool {
+        let Some(dialog) = &mut self.batch_dialog else {
+            return false;
+        };
+        let mut open = true;
+        let mut run = false;
+        egui::Window::new("Run in batch mode")
+            .open(&mut open)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("batch_dialog_grid")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Method");
+                        ui.text_edit_singleline(&mut dialog.method)
+                            .on_hover_text("Static method called with -executeMethod, leave empty to only import the project");
+                        ui.end_row();
+
+                        ui.label("Arguments");
+                        ui.text_edit_singleline(&mut dialog.args);
+                        ui.end_row();
+                    });
+                ui.add_space(VERTICAL_SPACING);
+                ui.label(egui::RichText::new("Projects").strong());
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for project in hub.projects.iter().filter(|p| p.is_valid) {
+                            let has_editor = hub.editor_for_project(project).is_some();
+                            let mut selected = dialog.projects.contains(&project.path);
+                            let response = ui.add_enabled(
+                                has_editor,
+                                egui::Checkbox::new(&mut selected, &project.title),
+                            );
+                            let response = if has_editor {
+                                response.on_hover_text(&project.path)
+                            } else {
+                                response.on_disabled_hover_text(format!(
+                                    "Missing {} Unity",
+                                    project.version
+                                ))
+                            };
+                            if response.changed() {
+                                if selected {
+                                    dialog.projects.insert(project.path.clone());
+                                } else {
+                                    dialog.projects.remove(&project.path);
+                                }
+                            }
+                        }
+                    });
+                ui.add_space(VERTICAL_SPACING);
+                run = ui
+                    .add_enabled(!dialog.projects.is_empty(), egui::Button::new("▶ Run"))
+                    .clicked();
+            });
+
+        if !run {
+            if !open {
+                self.batch_dialog = None;
+            }
+            return false;
+        }
+        let Some(dialog) = self.batch_dialog.take() else {
+            return false;
+        };
+        let args: Vec<String> = dialog.args.split_whitespace().map(str::to_string).collect();
+        let jobs: Vec<BatchJob> = hub
+            .projects
+            .iter()
+            .filter(|project| dialog.projects.contains(&project.path))
+            .filter_map(|project| {
+                let editor = hub.editor_for_project(project)?;
+                Some(
+                    BatchJob::new(&editor, project)
+                        .with_method(dialog.method.trim())
+                        .with_args(args.clone()),
+                )
+            })
+            .collect();
+        for job in jobs {
+            self.queue(job, None);
+        }
+        true
+    }
+
+    pub fn draw(&mut self, ui: &mut Ui) {
+        if self.jobs.is_empty() {
+            ui.label("No jobs yet, run one from the menu of a project.");
+            return;
+        }
+        let mut cancel = None;
+        TableBuilder::new(ui)
+            .id_salt("jobs_table")
+            .striped(true)
+            .vscroll(true)
+            .max_scroll_height(200.0)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::initial(150.0).at_least(100.0))
+            .column(Column::initial(200.0).at_least(100.0))
+            .column(Column::initial(140.0).at_least(100.0))
+            .column(Column::initial(80.0).at_least(60.0))
+            .column(Column::remainder().at_least(80.0))
+            .header(20.0, |mut header| {
+                for title in ["Project", "Method", "Status", "Time", ""] {
+                    header.col(|ui| {
+                        ui.strong(title);
+                    });
+                }
+            })
+            .body(|mut body| {
+                for view in self.jobs.iter().rev() {
+                    body.row(24.0, |mut row| {
+                        row.col(|ui| {
+                            let selected = self.selected == Some(view.id);
+                            if ui.selectable_label(selected, &view.job.title).clicked() {
+                                self.selected = Some(view.id);
+                            }
+                        });
+                        row.col(|ui| {
+                            ui.label(view.job.execute_method.as_deref().unwrap_or("Import"));
+                        });
+                        row.col(|ui| {
+                            draw_job_state(ui, &view.state);
+                        });
+                        row.col(|ui| {
+                            let duration = match &view.state {
+                                JobState::Queued => None,
+                                JobState::Running(started) => Some(started.elapsed()),
+                                JobState::Finished(Ok(outcome)) => Some(outcome.duration),
+                                JobState::Finished(Err(_)) => None,
+                            };
+                            if let Some(duration) = duration {
+                                ui.label(format_duration(duration));
+                            }
+                        });
+                        row.col(|ui| {
+                            if !matches!(view.state, JobState::Finished(_))
+                                && ui.button("✖ Cancel").clicked()
+                            {
+                                cancel = Some(view.id);
+                            }
+                        });
+                    });
+                }
+            });
+        if let Some(id) = cancel {
+            self.queue.cancel(id);
+        }
+        if self
+            .jobs
+            .iter()
+            .any(|view| matches!(view.state, JobState::Running(_)))
+        {
+            // Keeps the elapsed time ticking.
+            ui.ctx().request_repaint_after(Duration::from_secs(1));
+        }
+
+        let Some(view) = self.jobs.iter().find(|view| Some(view.id) == self.selected) else {
+            return;
+        };
+        ui.add_space(VERTICAL_SPACING);
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(&view.job.title).heading());
+            if ui.button("Open log file").clicked() {
+                open_in_file_manager(&view.job.log_file.to_string_lossy());
+            }
+        });
+        ui.label(
+            egui::RichText::new(view.job.command_line())
+                .small()
+                .monospace()
+                .weak(),
+        );
+        if !view.artifacts.is_empty() {
+            egui::CollapsingHeader::new(format!(
+                "{} artifact(s), {}",
+                view.artifacts.len(),
+                format_size(view.artifacts.iter().map(|a| a.size).sum())
+            ))
+            .show(ui, |ui| {
+                for artifact in &view.artifacts {
+                    ui.horizontal(|ui| {
+                        ui.label(artifact.path.to_string_lossy());
+                        ui.label(egui::RichText::new(format_size(artifact.size)).weak());
+                    });
+                }
+            });
+        } else if view.build.is_some() && matches!(view.state, JobState::Finished(_)) {
+            ui.label(egui::RichText::new("No artifacts produced").color(Color32::YELLOW));
+        }
+        match &view.test_run {
+            Some(Ok(run)) => draw_test_run(ui, run),
+            Some(Err(err)) => {
+                ui.label(egui::RichText::new(err).color(Color32::RED));
+            }
+            None => {}
+        }
+        egui::ScrollArea::vertical()
+            .id_salt("job_log")
+            .stick_to_bottom(true)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for line in &view.log {
+                    ui.label(egui::RichText::new(line).monospace().small());
+                }
+            });
+    }
+
+    pub fn draw_header(&mut self, ui: &mut Ui) {
+        add_header(ui);
+
+        let available_width = ui.available_width() - TOP_BUTTON_WIDTH * 2.0 - TOP_SIDE_MARGIN * 3.0;
+        ui.allocate_space(egui::vec2(available_width, HEADER_HEIGHT));
+        if ui
+            .add_sized([TOP_BUTTON_WIDTH, 30.0], egui::Button::new("✖ Cancel all"))
+            .clicked()
+        {
+            self.queue.cancel_all();
+        }
+        ui.allocate_space(egui::vec2(TOP_SIDE_MARGIN, 10.0));
+        if ui
+            .add_sized(
+                [TOP_BUTTON_WIDTH, 30.0],
+                egui::Button::new("🗑 Clear finished"),
+            )
+            .clicked()
+        {
+            self.jobs
+                .retain(|view| !matches!(view.state, JobState::Finished(_)));
+            if !self.jobs.iter().any(|view| Some(view.id) == self.selected) {
+                self.selected = None;
+            }
+        }
+        ui.allocate_space(egui::vec2(TOP_SIDE_MARGIN, 10.0));
+    }
+}
+
+fn draw_job_state(ui: &mut Ui, state: &JobState) {
+    match state {
+        JobState::Queued => {
+            ui.label(egui::RichText::new("Queued").weak());
+        }
+        JobState::Running(_) => {
+            ui.spinner();
+            ui.label("Running");
+        }
+        JobState::Finished(Ok(outcome)) if outcome.succeeded() => {
+            ui.label(egui::RichText::new("✔ Done").color(Color32::GREEN));
+        }
+        JobState::Finished(Ok(outcome)) if outcome.cancelled => {
+            ui.label(egui::RichText::new("Cancelled").weak());
+        }
+        JobState::Finished(Ok(outcome)) => {
+            let code = outcome
+                .exit_code
+                .map_or("killed".to_string(), |code| format!("exit code {}", code));
+            ui.label(egui::RichText::new(format!("✖ {}", code)).color(Color32::RED));
+        }
+        JobState::Finished(Err(JobError::Cancelled)) => {
+            ui.label(egui::RichText::new("Cancelled").weak());
+        }
+        JobState::Finished(Err(err)) => {
+            ui.label(egui::RichText::new("✖ Failed").color(Color32::RED))
+                .on_hover_text(err.to_string());
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::hub_client::{open_in_file_manager, text_setting};
+use eframe::{
+    egui::{self, Ui},
+    epaint::Color32,
+};
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use unity_hub_lib::{
+    editor_log::{self, LogClassifier, LogKind, LogLine},
+    log_tail::LogTail,
+};
+
+/// Lines kept by the log viewer.
+const MAX_LOG_VIEWER_LINES: usize = 10_000;
+
+/// Filters of the log viewer and the kinds of lines they toggle.
+const LOG_FILTERS: [(&str, &[LogKind]); 5] = [
+    ("Errors", &[LogKind::CompilerError, LogKind::Error]),
+    ("Exceptions", &[LogKind::Exception, LogKind::StackFrame]),
+    ("Warnings", &[LogKind::CompilerWarning, LogKind::Warning]),
+    ("Import", &[LogKind::Import]),
+    ("Other", &[LogKind::Info]),
+];
+
+/// Log file followed by the Logs tab.
+struct LogViewer {
+    /// Project the log belongs to, file locations in the log are relative to it.
+    project: Option<PathBuf>,
+    tail: LogTail,
+    classifier: LogClassifier,
+    lines: VecDeque<LogLine>,
+}
+
+impl LogViewer {
+    fn new(path: &Path, project: Option<PathBuf>) -> Self {
+        Self {
+            project,
+            tail: LogTail::new(path),
+            classifier: LogClassifier::default(),
+            lines: VecDeque::new(),
+        }
+    }
+
+    fn update(&mut self) {
+        for text in self.tail.read_lines() {
+            if self.lines.len() == MAX_LOG_VIEWER_LINES {
+                self.lines.pop_front();
+            }
+            self.lines.push_back(self.classifier.classify(&text));
+        }
+    }
+}
+
+/// Editor and project logs shown by the Logs tab.
+pub struct LogsPanel {
+    viewer: LogViewer,
+    /// Kinds of log lines hidden by the log viewer filters.
+    hidden_kinds: HashSet<LogKind>,
+    search: String,
+}
+
+impl Default for LogsPanel {
+    fn default() -> Self {
+        Self {
+            viewer: LogViewer::new(&editor_log::editor_log_path(), None),
+            hidden_kinds: HashSet::new(),
+            search: String::new(),
+        }
+    }
+}
+
+impl LogsPanel {
+    /// Follows `path` instead of the current log, `project` resolves file locations in it.
+    pub fn open(&mut self, path: &Path, project: Option<PathBuf>) {
+        self.viewer = LogViewer::new(path, project);
+    }
+
+    /// Editor and project logs followed live, with filters and links to source files.
+    /// Returns true when `ide_command` was edited.
+    pub fn draw(&mut self, ui: &mut Ui, ide_command: &mut String) -> bool {
+        self.viewer.update();
+        ui.ctx().request_repaint_after(Duration::from_millis(500));
+
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let current = self.viewer.tail.path().to_path_buf();
+            let mut sources = vec![editor_log::editor_log_path()];
+            if let Some(project) = &self.viewer.project {
+                sources.extend(editor_log::project_logs(project));
+            }
+            let mut selected = None;
+            egui::ComboBox::from_id_salt("log_source")
+                .width(300.0)
+                .selected_text(current.file_name().unwrap_or_default().to_string_lossy())
+                .show_ui(ui, |ui| {
+                    for source in sources {
+                        let name = source.to_string_lossy().to_string();
+                        if ui.selectable_label(source == current, name).clicked() {
+                            selected = Some(source);
+                        }
+                    }
+                });
+            if let Some(source) = selected {
+                self.viewer = LogViewer::new(&source, self.viewer.project.take());
+            }
+            if ui.button("Open log file").clicked() {
+                open_in_file_manager(&current.to_string_lossy());
+            }
+            ui.label("IDE");
+            changed = text_setting(ui, "ide_command", ide_command);
+        });
+        ui.horizontal(|ui| {
+            for (name, kinds) in LOG_FILTERS {
+                let count = self
+                    .viewer
+                    .lines
+                    .iter()
+                    .filter(|line| kinds.contains(&line.kind))
+                    .count();
+                let mut shown = !self.hidden_kinds.contains(&kinds[0]);
+                if ui
+                    .checkbox(&mut shown, format!("{} ({})", name, count))
+                    .changed()
+                {
+                    for kind in kinds {
+                        if shown {
+                            self.hidden_kinds.remove(kind);
+                        } else {
+                            self.hidden_kinds.insert(*kind);
+                        }
+                    }
+                }
+            }
+            ui.label("🔍");
+            ui.text_edit_singleline(&mut self.search);
+        });
+        ui.separator();
+
+        let search = self.search.to_lowercase();
+        let lines: Vec<&LogLine> = self
+            .viewer
+            .lines
+            .iter()
+            .filter(|line| !self.hidden_kinds.contains(&line.kind))
+            .filter(|line| search.is_empty() || line.text.to_lowercase().contains(&search))
+            .collect();
+        let project = self.viewer.project.as_deref();
+        let mut open = None;
+        let row_height = egui::TextStyle::Monospace.resolve(ui.style()).size + 4.0;
+        egui::ScrollArea::both()
+            .id_salt("log_lines")
+            .stick_to_bottom(true)
+            .auto_shrink([false, false])
+            .show_rows(ui, row_height, lines.len(), |ui, range| {
+                for line in &lines[range] {
+                    let text = egui::RichText::new(&line.text).monospace();
+                    let text = match line.kind {
+                        LogKind::CompilerError | LogKind::Error | LogKind::Exception => {
+                            text.color(Color32::RED)
+                        }
+                        LogKind::CompilerWarning | LogKind::Warning => text.color(Color32::YELLOW),
+                        LogKind::StackFrame | LogKind::Import => text.weak(),
+                        LogKind::Info => text,
+                    };
+                    match (&line.location, project) {
+                        (Some(location), Some(project)) => {
+                            if ui
+                                .link(text)
+                                .on_hover_text(format!(
+                                    "Open {}:{} in the IDE",
+                                    location.file, location.line
+                                ))
+                                .clicked()
+                            {
+                                open = Some((project.to_path_buf(), location.clone()));
+                            }
+                        }
+                        _ => {
+                            ui.label(text);
+                        }
+                    }
+                }
+            });
+
+        if let Some((project, location)) = open {
+            if let Err(err) = editor_log::open_in_ide(ide_command, &project, &location) {
+                rfd::MessageDialog::new()
+                    .set_title("Failed to open the IDE")
+                    .set_description(err.to_string())
+                    .set_level(rfd::MessageLevel::Error)
+                    .show();
+            }
+        }
+        changed
+    }
+}
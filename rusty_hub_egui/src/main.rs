@@ -3,8 +3,13 @@
 use consts::{APP_NAME, VERSION};
 use eframe::egui;
 
+mod account_panel;
+mod builds_panel;
 mod consts;
 mod hub_client;
+mod jobs_panel;
+mod logs_panel;
+mod tests_panel;
 mod window_tab;
 
 fn main() -> eframe::Result<()> {
@@ -0,0 +1,100 @@
+use crate::jobs_panel::JobsPanel;
+use eframe::{
+    egui::{self, Ui},
+    epaint::Color32,
+};
+use std::path::PathBuf;
+use unity_hub_lib::{
+    hub::Hub,
+    test_runner::{self, TestCase, TestOutcome, TestPlatform, TestRun, TestSuite},
+    unity_project::UnityProject,
+};
+
+/// Queues a test run of `project`, returns false when its editor isn't installed.
+pub fn queue_tests(
+    hub: &Hub,
+    jobs: &mut JobsPanel,
+    project: &UnityProject,
+    platform: TestPlatform,
+) -> bool {
+    let Some(editor) = hub.editor_for_project(project) else {
+        return false;
+    };
+    let job = test_runner::test_job(&editor, project, platform, None);
+    let results = PathBuf::from(test_runner::results_path(&job));
+    // Results of an earlier run with the same name would be shown as the new ones.
+    let _ = std::fs::remove_file(&results);
+    jobs.queue_test_run(job, results);
+    true
+}
+
+/// Test totals followed by the tree of suites, failed ones open.
+pub fn draw_test_run(ui: &mut Ui, run: &TestRun) {
+    ui.horizontal(|ui| {
+        let summary = egui::RichText::new(format!(
+            "{} test(s): {} passed, {} failed, {} skipped",
+            run.total, run.passed, run.failed, run.skipped
+        ));
+        ui.label(if run.succeeded() {
+            summary.color(Color32::GREEN)
+        } else {
+            summary.color(Color32::RED)
+        });
+        ui.label(egui::RichText::new(format!("{:.2}s", run.duration)).weak());
+    });
+    egui::CollapsingHeader::new("Test results")
+        .default_open(!run.succeeded())
+        .show(ui, |ui| {
+            for suite in &run.suites {
+                draw_test_suite(ui, suite);
+            }
+        });
+}
+
+fn draw_test_suite(ui: &mut Ui, suite: &TestSuite) {
+    egui::CollapsingHeader::new(test_outcome_text(suite.outcome, &suite.name))
+        .id_salt(&suite.full_name)
+        .default_open(suite.outcome == TestOutcome::Failed)
+        .show(ui, |ui| {
+            for child in &suite.suites {
+                draw_test_suite(ui, child);
+            }
+            for case in &suite.cases {
+                draw_test_case(ui, case);
+            }
+        });
+}
+
+fn draw_test_case(ui: &mut Ui, case: &TestCase) {
+    let label = test_outcome_text(case.outcome, &case.name);
+    let details = [&case.message, &case.stack_trace, &case.output]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>();
+    if details.is_empty() {
+        ui.horizontal(|ui| {
+            ui.label(label);
+            ui.label(egui::RichText::new(format!("{:.3}s", case.duration)).weak());
+        });
+        return;
+    }
+    egui::CollapsingHeader::new(label)
+        .id_salt(&case.full_name)
+        .show(ui, |ui| {
+            for text in details {
+                ui.label(egui::RichText::new(text).monospace().small());
+            }
+        });
+}
+
+fn test_outcome_text(outcome: TestOutcome, name: &str) -> egui::RichText {
+    match outcome {
+        TestOutcome::Passed => egui::RichText::new(format!("✔ {}", name)).color(Color32::GREEN),
+        TestOutcome::Failed => egui::RichText::new(format!("✖ {}", name)).color(Color32::RED),
+        TestOutcome::Skipped => egui::RichText::new(format!("⏭ {}", name)).weak(),
+        TestOutcome::Inconclusive => {
+            egui::RichText::new(format!("? {}", name)).color(Color32::YELLOW)
+        }
+    }
+}
@@ -2,4 +2,7 @@
 pub enum WindowTab {
     Projects,
     Editors,
+    Jobs,
+    Logs,
+    Account,
 }
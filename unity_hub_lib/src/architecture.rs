@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::Path;
+
+/// Bytes read from the beginning of an executable, enough for PE, ELF and Mach-O headers.
+const HEADER_SIZE: u64 = 64 * 1024;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Architecture {
+    X86,
+    X86_64,
+    Arm64,
+    /// Mach-O binary containing both Intel and Apple Silicon code.
+    Universal,
+    #[default]
+    Unknown,
+}
+
+impl Architecture {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Architecture::X86 => "x86",
+            Architecture::X86_64 => "x86_64",
+            Architecture::Arm64 => "arm64",
+            Architecture::Universal => "universal",
+            Architecture::Unknown => "unknown",
+        }
+    }
+
+    /// Reads architecture from headers of the executable at `exe_path`.
+    pub fn detect(exe_path: &Path) -> Architecture {
+        let mut header = Vec::new();
+        let read = std::fs::File::open(exe_path)
+            .and_then(|file| file.take(HEADER_SIZE).read_to_end(&mut header));
+        match read {
+            Ok(_) => Architecture::from_header(&header),
+            Err(_) => Architecture::Unknown,
+        }
+    }
+
+    /// Recognizes architecture from the first bytes of a PE, ELF or Mach-O file.
+    pub fn from_header(bytes: &[u8]) -> Architecture {
+        if bytes.starts_with(b"MZ") {
+            Architecture::from_pe(bytes)
+        } else if bytes.starts_with(b"\x7fELF") {
+            Architecture::from_elf(bytes)
+        } else {
+            Architecture::from_mach_o(bytes)
+        }
+    }
+
+    /// Architecture of the machine the hub runs on, ignoring Rosetta translation on macOS.
+    pub fn host() -> Architecture {
+        #[cfg(target_os = "macos")]
+        {
+            let arm64 = std::process::Command::new("sysctl")
+                .args(["-n", "hw.optional.arm64"])
+                .output()
+                .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "1");
+            if arm64 {
+                return Architecture::Arm64;
+            }
+        }
+        match std::env::consts::ARCH {
+            "x86" => Architecture::X86,
+            "x86_64" => Architecture::X86_64,
+            "aarch64" => Architecture::Arm64,
+            _ => Architecture::Unknown,
+        }
+    }
+
+    /// False when the editor would run under emulation or not at all on the `host`.
+    pub fn runs_natively_on(&self, host: Architecture) -> bool {
+        match (self, host) {
+            (Architecture::Unknown, _) | (_, Architecture::Unknown) => true,
+            (Architecture::Universal, _) => true,
+            (Architecture::X86, Architecture::X86_64) => true,
+            (editor, host) => *editor == host,
+        }
+    }
+
+    fn from_pe(bytes: &[u8]) -> Architecture {
+        use exe::{ImageFileMachine, NTHeaders, VecPE, PE};
+
+        let image = VecPE::from_disk_data(bytes);
+        let machine = match image.get_valid_nt_headers() {
+            Ok(NTHeaders::NTHeaders32(headers)) => headers.file_header.machine,
+            Ok(NTHeaders::NTHeaders64(headers)) => headers.file_header.machine,
+            Err(_) => return Architecture::Unknown,
+        };
+        match machine {
+            m if m == ImageFileMachine::I386 as u16 => Architecture::X86,
+            m if m == ImageFileMachine::AMD64 as u16 => Architecture::X86_64,
+            m if m == ImageFileMachine::ARM64 as u16 => Architecture::Arm64,
+            _ => Architecture::Unknown,
+        }
+    }
+
+    fn from_elf(bytes: &[u8]) -> Architecture {
+        const EM_386: u16 = 3;
+        const EM_X86_64: u16 = 62;
+        const EM_AARCH64: u16 = 183;
+
+        let Some(machine) = bytes.get(18..20) else {
+            return Architecture::Unknown;
+        };
+        let machine = match bytes.get(5) {
+            Some(2) => u16::from_be_bytes([machine[0], machine[1]]),
+            _ => u16::from_le_bytes([machine[0], machine[1]]),
+        };
+        match machine {
+            EM_386 => Architecture::X86,
+            EM_X86_64 => Architecture::X86_64,
+            EM_AARCH64 => Architecture::Arm64,
+            _ => Architecture::Unknown,
+        }
+    }
+
+    fn from_mach_o(bytes: &[u8]) -> Architecture {
+        const FAT_MAGIC: u32 = 0xCAFE_BABE;
+        const MH_MAGIC: u32 = 0xFEED_FACE;
+        const MH_MAGIC_64: u32 = 0xFEED_FACF;
+
+        let read_u32 = |offset: usize, big_endian: bool| {
+            bytes.get(offset..offset + 4).map(|b| {
+                let b = [b[0], b[1], b[2], b[3]];
+                if big_endian {
+                    u32::from_be_bytes(b)
+                } else {
+                    u32::from_le_bytes(b)
+                }
+            })
+        };
+
+        match read_u32(0, true) {
+            // Fat header and its entries are always big endian.
+            Some(FAT_MAGIC) => {
+                let count = read_u32(4, true).unwrap_or_default() as usize;
+                let archs: Vec<Architecture> = (0..count.min(16))
+                    .filter_map(|i| read_u32(8 + i * 20, true))
+                    .map(Architecture::from_cpu_type)
+                    .collect();
+                let intel = archs.contains(&Architecture::X86_64);
+                let arm = archs.contains(&Architecture::Arm64);
+                match (intel, arm) {
+                    (true, true) => Architecture::Universal,
+                    (true, false) => Architecture::X86_64,
+                    (false, true) => Architecture::Arm64,
+                    _ => archs.first().copied().unwrap_or_default(),
+                }
+            }
+            Some(magic) if magic == MH_MAGIC.swap_bytes() || magic == MH_MAGIC_64.swap_bytes() => {
+                read_u32(4, false).map_or(Architecture::Unknown, Architecture::from_cpu_type)
+            }
+            Some(MH_MAGIC | MH_MAGIC_64) => {
+                read_u32(4, true).map_or(Architecture::Unknown, Architecture::from_cpu_type)
+            }
+            _ => Architecture::Unknown,
+        }
+    }
+
+    fn from_cpu_type(cpu_type: u32) -> Architecture {
+        const CPU_TYPE_X86: u32 = 7;
+        const CPU_ARCH_ABI64: u32 = 0x0100_0000;
+        const CPU_TYPE_ARM: u32 = 12;
+
+        match cpu_type {
+            CPU_TYPE_X86 => Architecture::X86,
+            t if t == CPU_TYPE_X86 | CPU_ARCH_ABI64 => Architecture::X86_64,
+            t if t == CPU_TYPE_ARM | CPU_ARCH_ABI64 => Architecture::Arm64,
+            _ => Architecture::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// DOS stub pointing at NT headers with the given machine and optional header magic.
+    fn pe(machine: u16, optional_magic: u16) -> Vec<u8> {
+        let mut bytes = vec![0u8; 0x40];
+        bytes[..2].copy_from_slice(b"MZ");
+        bytes[0x3C..0x40].copy_from_slice(&0x40u32.to_le_bytes());
+        bytes.extend_from_slice(b"PE\0\0");
+        let mut file_header = [0u8; 20];
+        file_header[..2].copy_from_slice(&machine.to_le_bytes());
+        file_header[16..18].copy_from_slice(&0xF0u16.to_le_bytes());
+        bytes.extend_from_slice(&file_header);
+        let mut optional_header = [0u8; 0xF0];
+        optional_header[..2].copy_from_slice(&optional_magic.to_le_bytes());
+        bytes.extend_from_slice(&optional_header);
+        bytes
+    }
+
+    fn elf(machine: u16, big_endian: bool) -> Vec<u8> {
+        let mut bytes = vec![0u8; 64];
+        bytes[..4].copy_from_slice(b"\x7fELF");
+        bytes[4] = 2;
+        bytes[5] = if big_endian { 2 } else { 1 };
+        let machine = if big_endian {
+            machine.to_be_bytes()
+        } else {
+            machine.to_le_bytes()
+        };
+        bytes[18..20].copy_from_slice(&machine);
+        bytes
+    }
+
+    #[test]
+    fn reads_pe_machine() {
+        assert_eq!(
+            Architecture::from_header(&pe(0x8664, 0x20B)),
+            Architecture::X86_64
+        );
+        assert_eq!(
+            Architecture::from_header(&pe(0xAA64, 0x20B)),
+            Architecture::Arm64
+        );
+        assert_eq!(
+            Architecture::from_header(&pe(0x14C, 0x10B)),
+            Architecture::X86
+        );
+    }
+
+    #[test]
+    fn reads_elf_machine_in_both_byte_orders() {
+        assert_eq!(
+            Architecture::from_header(&elf(62, false)),
+            Architecture::X86_64
+        );
+        assert_eq!(
+            Architecture::from_header(&elf(183, false)),
+            Architecture::Arm64
+        );
+        assert_eq!(
+            Architecture::from_header(&elf(62, true)),
+            Architecture::X86_64
+        );
+        assert_eq!(
+            Architecture::from_header(&elf(183, true)),
+            Architecture::Arm64
+        );
+    }
+
+    #[test]
+    fn reads_thin_mach_o_cpu_type() {
+        let mut intel = 0xFEED_FACFu32.to_le_bytes().to_vec();
+        intel.extend_from_slice(&0x0100_0007u32.to_le_bytes());
+        assert_eq!(Architecture::from_header(&intel), Architecture::X86_64);
+
+        let mut arm = 0xFEED_FACFu32.to_le_bytes().to_vec();
+        arm.extend_from_slice(&0x0100_000Cu32.to_le_bytes());
+        assert_eq!(Architecture::from_header(&arm), Architecture::Arm64);
+
+        let mut big_endian = 0xFEED_FACEu32.to_be_bytes().to_vec();
+        big_endian.extend_from_slice(&7u32.to_be_bytes());
+        assert_eq!(Architecture::from_header(&big_endian), Architecture::X86);
+    }
+
+    #[test]
+    fn reads_fat_mach_o_slices() {
+        let fat = |cpu_types: &[u32]| {
+            let mut bytes = 0xCAFE_BABEu32.to_be_bytes().to_vec();
+            bytes.extend_from_slice(&(cpu_types.len() as u32).to_be_bytes());
+            for cpu_type in cpu_types {
+                let mut entry = [0u8; 20];
+                entry[..4].copy_from_slice(&cpu_type.to_be_bytes());
+                bytes.extend_from_slice(&entry);
+            }
+            bytes
+        };
+        assert_eq!(
+            Architecture::from_header(&fat(&[0x0100_0007, 0x0100_000C])),
+            Architecture::Universal
+        );
+        assert_eq!(
+            Architecture::from_header(&fat(&[0x0100_000C])),
+            Architecture::Arm64
+        );
+        assert_eq!(Architecture::from_header(&fat(&[])), Architecture::Unknown);
+    }
+
+    #[test]
+    fn truncated_or_unknown_headers_are_unknown() {
+        let mut truncated_pe = pe(0x8664, 0x20B);
+        truncated_pe.truncate(0x50);
+        assert_eq!(
+            Architecture::from_header(&truncated_pe),
+            Architecture::Unknown
+        );
+        assert_eq!(
+            Architecture::from_header(&elf(62, false)[..19]),
+            Architecture::Unknown
+        );
+        assert_eq!(
+            Architecture::from_header(&0xFEED_FACFu32.to_le_bytes()),
+            Architecture::Unknown
+        );
+        assert_eq!(Architecture::from_header(b""), Architecture::Unknown);
+        assert_eq!(
+            Architecture::from_header(b"#!/bin/sh\necho hi\n"),
+            Architecture::Unknown
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::log_tail::LogTail;
+use crate::project_scan::CancelToken;
+use crate::unity_editor::UnityEditor;
+use crate::unity_project::UnityProject;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// How often the log file is read while the editor is running.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Jobs created by this process, keeps log file names apart.
+static JOB_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub type JobId = u64;
+
+/// Headless editor run for a single project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BatchJob {
+    pub title: String,
+    pub editor_exe: String,
+    pub project_path: String,
+    /// Static method called with `-executeMethod`, e.g. `Tools.Reimport.Run`.
+    pub execute_method: Option<String>,
+    pub args: Vec<String>,
+    pub log_file: PathBuf,
+    pub nographics: bool,
+    /// Adds `-quit`, turned off for commands that quit on their own like `-runTests`.
+    pub quit: bool,
+}
+
+#[derive(Debug)]
+pub enum JobError {
+    Spawn(std::io::Error),
+    Wait(std::io::Error),
+    /// Cancelled before the editor was started.
+    Cancelled,
+}
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobError::Spawn(err) => write!(f, "Failed to start the editor: {}", err),
+            JobError::Wait(err) => write!(f, "Failed to wait for the editor: {}", err),
+            JobError::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for JobError {}
+
+#[derive(Debug, Clone)]
+pub struct JobOutcome {
+    /// `None` when the editor was killed by a signal.
+    pub exit_code: Option<i32>,
+    pub duration: Duration,
+    pub log_file: PathBuf,
+    /// The editor was killed because the job was cancelled.
+    pub cancelled: bool,
+}
+
+impl JobOutcome {
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0) && !self.cancelled
+    }
+}
+
+#[derive(Debug)]
+pub enum JobEvent {
+    Started(JobId),
+    /// Line written to the log file of the job.
+    Log(JobId, String),
+    Finished(JobId, Result<JobOutcome, JobError>),
+}
+
+impl BatchJob {
+    /// Runs `editor` with `project` and quits, which imports and compiles the project.
+    /// The log goes to the project `Logs` folder.
+    pub fn new(editor: &UnityEditor, project: &UnityProject) -> Self {
+        // Jobs created in the same millisecond, e.g. a test run per platform, still get
+        // their own log and results file.
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_millis());
+        let count = JOB_COUNT.fetch_add(1, Ordering::Relaxed);
+        Self {
+            title: project.title.clone(),
+            editor_exe: editor.exe_path.clone(),
+            project_path: project.path.clone(),
+            execute_method: None,
+            args: Vec::new(),
+            log_file: Path::new(&project.path)
+                .join("Logs")
+                .join(format!("batch-{}-{}.log", stamp, count)),
+            nographics: true,
+            quit: true,
+        }
+    }
+
+    pub fn with_method(mut self, method: &str) -> Self {
+        self.execute_method = Some(method.to_string()).filter(|method| !method.is_empty());
+        self
+    }
+
+    pub fn with_args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn command_args(&self) -> Vec<String> {
+        let mut args = vec!["-batchmode".to_string()];
+        if self.quit {
+            args.push("-quit".to_string());
+        }
+        if self.nographics {
+            args.push("-nographics".to_string());
+        }
+        args.extend([
+            "-projectPath".to_string(),
+            self.project_path.clone(),
+            "-logFile".to_string(),
+            self.log_file.to_string_lossy().to_string(),
+        ]);
+        if let Some(method) = &self.execute_method {
+            args.extend(["-executeMethod".to_string(), method.clone()]);
+        }
+        args.extend(self.args.iter().cloned());
+        args
+    }
+
+    /// Command line as it would be typed in a shell, for display only.
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.editor_exe.clone())
+            .chain(self.command_args())
+            .map(|arg| {
+                if arg.contains(' ') {
+                    format!("\"{}\"", arg)
+                } else {
+                    arg
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Starts the editor and waits for it, reporting lines written to the log file as they
+/// appear. The editor is killed once `cancel` is set.
+pub fn run(
+    job: &BatchJob,
+    cancel: &CancelToken,
+    mut on_line: impl FnMut(String),
+) -> Result<JobOutcome, JobError> {
+    if cancel.is_cancelled() {
+        return Err(JobError::Cancelled);
+    }
+    if let Some(dir) = job.log_file.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    // Unity appends to an existing log file on some platforms.
+    let _ = std::fs::remove_file(&job.log_file);
+
+    let started = Instant::now();
+    let mut child = Command::new(&job.editor_exe)
+        .args(job.command_args())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(JobError::Spawn)?;
+    let mut tail = LogTail::new(&job.log_file);
+    let mut cancelled = false;
+
+    let status = loop {
+        tail.read_lines().into_iter().for_each(&mut on_line);
+        if !cancelled && cancel.is_cancelled() {
+            cancelled = true;
+            let _ = child.kill();
+        }
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => std::thread::sleep(POLL_INTERVAL),
+            Err(err) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(JobError::Wait(err));
+            }
+        }
+    };
+    tail.read_remaining().into_iter().for_each(&mut on_line);
+
+    Ok(JobOutcome {
+        exit_code: status.code(),
+        duration: started.elapsed(),
+        log_file: job.log_file.clone(),
+        cancelled,
+    })
+}
+
+/// Runs batch jobs one after another on a background thread, reporting their progress to
+/// `on_event`. The worker thread ends once the queue is dropped and queued jobs are done.
+pub struct JobQueue {
+    sender: mpsc::Sender<(JobId, BatchJob, CancelToken)>,
+    cancels: Arc<Mutex<HashMap<JobId, CancelToken>>>,
+    next_id: JobId,
+}
+
+impl JobQueue {
+    pub fn new(on_event: impl Fn(JobEvent) + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel::<(JobId, BatchJob, CancelToken)>();
+        let cancels: Arc<Mutex<HashMap<JobId, CancelToken>>> = Arc::default();
+        let worker_cancels = cancels.clone();
+        std::thread::spawn(move || {
+            for (id, job, cancel) in receiver {
+                if !cancel.is_cancelled() {
+                    on_event(JobEvent::Started(id));
+                }
+                let result = run(&job, &cancel, |line| on_event(JobEvent::Log(id, line)));
+                if let Ok(mut cancels) = worker_cancels.lock() {
+                    cancels.remove(&id);
+                }
+                on_event(JobEvent::Finished(id, result));
+            }
+        });
+        Self {
+            sender,
+            cancels,
+            next_id: 0,
+        }
+    }
+
+    /// Adds the job at the end of the queue.
+    pub fn push(&mut self, job: BatchJob) -> JobId {
+        self.next_id += 1;
+        let id = self.next_id;
+        let cancel = CancelToken::default();
+        if let Ok(mut cancels) = self.cancels.lock() {
+            cancels.insert(id, cancel.clone());
+        }
+        let _ = self.sender.send((id, job, cancel));
+        id
+    }
+
+    /// Kills the job when it's running or skips it when it's still waiting.
+    pub fn cancel(&self, id: JobId) {
+        if let Some(cancel) = self.cancels.lock().ok().and_then(|c| c.get(&id).cloned()) {
+            cancel.cancel();
+        }
+    }
+
+    pub fn cancel_all(&self) {
+        if let Ok(cancels) = self.cancels.lock() {
+            cancels.values().for_each(CancelToken::cancel);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::batch::BatchJob;
+use crate::unity_editor::UnityEditor;
+use crate::unity_project::UnityProject;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuildTarget {
+    Windows64,
+    Linux64,
+    MacOS,
+    Android,
+    IOS,
+    WebGL,
+}
+
+impl BuildTarget {
+    pub const ALL: [BuildTarget; 6] = [
+        BuildTarget::Windows64,
+        BuildTarget::Linux64,
+        BuildTarget::MacOS,
+        BuildTarget::Android,
+        BuildTarget::IOS,
+        BuildTarget::WebGL,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuildTarget::Windows64 => "Windows",
+            BuildTarget::Linux64 => "Linux",
+            BuildTarget::MacOS => "macOS",
+            BuildTarget::Android => "Android",
+            BuildTarget::IOS => "iOS",
+            BuildTarget::WebGL => "WebGL",
+        }
+    }
+
+    /// Value of the `-buildTarget` argument.
+    pub fn cli_name(&self) -> &'static str {
+        match self {
+            BuildTarget::Windows64 => "Win64",
+            BuildTarget::Linux64 => "Linux64",
+            BuildTarget::MacOS => "OSXUniversal",
+            BuildTarget::Android => "Android",
+            BuildTarget::IOS => "iOS",
+            BuildTarget::WebGL => "WebGL",
+        }
+    }
+
+    /// Argument building the player without a build method, only standalone targets have one.
+    pub fn player_build_arg(&self) -> Option<&'static str> {
+        match self {
+            BuildTarget::Windows64 => Some("-buildWindows64Player"),
+            BuildTarget::Linux64 => Some("-buildLinux64Player"),
+            BuildTarget::MacOS => Some("-buildOSXUniversalPlayer"),
+            BuildTarget::Android | BuildTarget::IOS | BuildTarget::WebGL => None,
+        }
+    }
+}
+
+/// Saved way of building a project for a single target.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BuildProfile {
+    pub name: String,
+    pub target: BuildTarget,
+    /// Executable for the default player build, folder passed as `-outputPath` to a build
+    /// method. Relative paths start at the project folder.
+    pub output_path: String,
+    /// Static method doing the build, the default player build when empty.
+    #[serde(default)]
+    pub build_method: String,
+    /// Passed to the build method as `-scriptingDefines A;B`.
+    #[serde(default)]
+    pub scripting_defines: Vec<String>,
+    /// Passed to the build method as `-development`.
+    #[serde(default)]
+    pub development: bool,
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// No editor matches the project version.
+    MissingEditor(String),
+    EmptyOutputPath,
+    /// The target has no default player build, see `BuildTarget::player_build_arg`.
+    MethodRequired(BuildTarget),
+    /// Defines and development builds are only passed to build methods.
+    NeedsMethod(&'static str),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingEditor(version) => write!(f, "Editor {} is not installed", version),
+            BuildError::EmptyOutputPath => write!(f, "Output path is empty"),
+            BuildError::MethodRequired(target) => {
+                write!(f, "{} can't be built without a build method", target.name())
+            }
+            BuildError::NeedsMethod(option) => {
+                write!(f, "{} is only supported with a build method", option)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// File produced by a build.
+#[derive(Debug, Clone)]
+pub struct Artifact {
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+impl BuildProfile {
+    pub fn new(target: BuildTarget) -> Self {
+        let output_path = match target {
+            BuildTarget::Windows64 => "Builds/Windows/Game.exe",
+            BuildTarget::Linux64 => "Builds/Linux/Game.x86_64",
+            BuildTarget::MacOS => "Builds/macOS/Game.app",
+            BuildTarget::Android => "Builds/Android",
+            BuildTarget::IOS => "Builds/iOS",
+            BuildTarget::WebGL => "Builds/WebGL",
+        };
+        Self {
+            name: target.name().to_string(),
+            target,
+            output_path: output_path.to_string(),
+            build_method: String::new(),
+            scripting_defines: Vec::new(),
+            development: false,
+            extra_args: Vec::new(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), BuildError> {
+        if self.output_path.trim().is_empty() {
+            return Err(BuildError::EmptyOutputPath);
+        }
+        if self.build_method.is_empty() {
+            if self.target.player_build_arg().is_none() {
+                return Err(BuildError::MethodRequired(self.target));
+            }
+            if !self.scripting_defines.is_empty() {
+                return Err(BuildError::NeedsMethod("Scripting defines"));
+            }
+            if self.development {
+                return Err(BuildError::NeedsMethod("Development build"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Absolute output path for `project`.
+    pub fn output_for(&self, project: &UnityProject) -> PathBuf {
+        Path::new(&project.path).join(self.output_path.trim())
+    }
+
+    /// Batch job building `project` with `editor`.
+    pub fn job(
+        &self,
+        editor: &UnityEditor,
+        project: &UnityProject,
+    ) -> Result<BatchJob, BuildError> {
+        self.validate()?;
+        let output = self.output_for(project).to_string_lossy().to_string();
+        let mut args = vec![
+            "-buildTarget".to_string(),
+            self.target.cli_name().to_string(),
+        ];
+        let mut job = BatchJob::new(editor, project);
+        match self.target.player_build_arg() {
+            Some(build_arg) if self.build_method.is_empty() => {
+                args.extend([build_arg.to_string(), output]);
+            }
+            _ => {
+                job = job.with_method(&self.build_method);
+                args.extend(["-outputPath".to_string(), output]);
+                if !self.scripting_defines.is_empty() {
+                    args.extend([
+                        "-scriptingDefines".to_string(),
+                        self.scripting_defines.join(";"),
+                    ]);
+                }
+                if self.development {
+                    args.push("-development".to_string());
+                }
+            }
+        }
+        args.extend(self.extra_args.iter().cloned());
+        job.title = format!("{} ({})", project.title, self.name);
+        Ok(job.with_args(args))
+    }
+}
+
+/// Files in the build output modified since `since`, an executable output counts its folder.
+pub fn collect_artifacts(output: &Path, since: SystemTime) -> Vec<Artifact> {
+    let dir = if output.is_file() {
+        output.parent().unwrap_or(output)
+    } else {
+        output
+    };
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            let modified = meta.modified().ok()?;
+            (modified >= since).then(|| Artifact {
+                path: entry.into_path(),
+                size: meta.len(),
+            })
+        })
+        .collect()
+}
@@ -1,77 +1,224 @@
-use crate::{consts, unity_editor::UnityEditor};
+use crate::{
+    consts::{DEFAULT_IDE_COMMAND, RELEASE_CATALOG_URL},
+    discovery::DiscoverySource,
+    editor_layout::EditorLayout,
+    project_root::{ProjectRoot, ProjectRootReport},
+    project_scan::ScanOptions,
+    search_path::{SearchPath, SearchPathReport},
+    unity_editor::UnityEditor,
+};
 use dpc_pariter::IteratorExt;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use walkdir::{DirEntry, WalkDir};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use walkdir::WalkDir;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Configuration {
-    pub unity_search_paths: Vec<String>,
+    pub unity_search_paths: Vec<SearchPath>,
     pub editors_configurations: Vec<UnityEditor>,
+    /// Look for editors outside of search paths, see `DiscoverySource`.
+    #[serde(default = "default_auto_discovery")]
+    pub auto_discovery: bool,
+    /// Editor executables registered one by one instead of through a parent folder.
+    #[serde(default)]
+    pub editor_executables: Vec<String>,
+    /// Endpoint of the editor release catalog, see `ReleaseCatalog`.
+    #[serde(default = "default_release_catalog_url")]
+    pub release_catalog_url: String,
+    /// Host or directory replacing `download.unity3d.com` when installing, see `installer::resolve_url`.
+    #[serde(default)]
+    pub download_mirror: String,
+    /// Folders rescanned for projects on startup, see `Hub::scan_project_roots`.
+    #[serde(default)]
+    pub project_roots: Vec<ProjectRoot>,
+    /// How folders are walked when looking for projects.
+    #[serde(default)]
+    pub project_scan: ScanOptions,
+    /// Opens a source file at a line from the log viewer, see `DEFAULT_IDE_COMMAND`.
+    #[serde(default = "default_ide_command")]
+    pub ide_command: String,
+    /// Projects removed from the list, scans and the registry don't add them again.
+    #[serde(default)]
+    pub ignored_projects: Vec<String>,
+    /// Results of the last rebuild, keyed by `SearchPath::path`.
+    #[serde(skip)]
+    pub search_reports: HashMap<String, SearchPathReport>,
+    #[serde(skip)]
+    pub discovery_reports: Vec<(DiscoverySource, SearchPathReport)>,
+    /// Results of the last project root scan, keyed by `ProjectRoot::path`.
+    #[serde(skip)]
+    pub project_root_reports: HashMap<String, ProjectRootReport>,
+}
+
+fn default_auto_discovery() -> bool {
+    true
+}
+
+fn default_release_catalog_url() -> String {
+    RELEASE_CATALOG_URL.to_string()
+}
+
+fn default_ide_command() -> String {
+    DEFAULT_IDE_COMMAND.to_string()
 }
 
 impl Configuration {
+    /// Looks for editors again, reusing the ones that didn't change since the last rebuild.
     pub fn rebuild(&mut self) {
+        self.rebuild_editors(false);
+    }
+
+    /// Looks for editors again and parses every one of them.
+    pub fn force_rebuild(&mut self) {
+        self.rebuild_editors(true);
+    }
+
+    fn rebuild_editors(&mut self, force: bool) {
         let paths = self.get_unity_paths();
+        let previous = std::mem::take(&mut self.editors_configurations);
+        let cached: Arc<HashMap<String, UnityEditor>> = Arc::new(if force {
+            HashMap::new()
+        } else {
+            previous
+                .iter()
+                .map(|editor| (editor.base_path.clone(), editor.clone()))
+                .collect()
+        });
         self.editors_configurations = paths
             .into_iter()
-            .parallel_map(|path| UnityEditor::new(&path))
+            .parallel_map(move |path| match cached.get(&path) {
+                Some(editor) if editor.is_up_to_date() => Some(editor.clone()),
+                _ => UnityEditor::new(&path),
+            })
             .parallel_filter(|editor| editor.is_some())
             .parallel_map(|editor| editor.unwrap())
             .collect();
+
+        // Computing size takes long, keep it for editors that were not reinstalled.
+        for editor in &mut self.editors_configurations {
+            if let Some(old) = previous.iter().find(|old| *old == editor) {
+                if old.install_date == editor.install_date {
+                    editor.installed_size = old.installed_size;
+                }
+            }
+        }
     }
-    pub fn get_unity_paths(&self) -> Vec<String> {
+
+    pub fn get_unity_paths(&mut self) -> Vec<String> {
         let mut paths = Vec::new();
+        let mut known = HashSet::new();
+        self.search_reports.clear();
+        self.discovery_reports.clear();
+
+        for search_path in &self.unity_search_paths {
+            if !search_path.enabled {
+                continue;
+            }
+            let (found, report) = Configuration::search_for_editor(search_path);
+            for path in found {
+                if known.insert(PathBuf::from(&path)) {
+                    paths.push(path);
+                }
+            }
+            self.search_reports.insert(search_path.path.clone(), report);
+        }
 
-        for path in &self.unity_search_paths {
-            paths.extend(Configuration::search_for_editor(path.as_str()));
+        let mut sources = vec![DiscoverySource::Manual];
+        if self.auto_discovery {
+            sources.extend(DiscoverySource::AUTOMATIC);
+        }
+        for source in sources {
+            let (found, mut report) = source.discover(self);
+            // Only count editors that were not already found elsewhere.
+            report.editors = 0;
+            for path in found {
+                if known.insert(path.clone()) {
+                    paths.push(path.to_string_lossy().into());
+                    report.editors += 1;
+                }
+            }
+            self.discovery_reports.push((source, report));
         }
 
         paths
     }
 
-    fn is_unity_dir(entry: &DirEntry) -> bool {
-        #[cfg(windows)]
-        let uninstall_exists = entry.path().join("Uninstall.exe").exists();
-        #[cfg(unix)]
-        let uninstall_exists = true; // just check that on windows only
-        let unity_exe_exists = entry.path().join(consts::UNITY_EXE_NAME).exists();
-        uninstall_exists && unity_exe_exists
+    pub fn is_unity_dir(path: &Path) -> bool {
+        EditorLayout::detect(path).is_some()
     }
 
-    fn search_for_editor(path: &str) -> Vec<String> {
-        let path_exists = std::fs::metadata(path).is_ok();
-        if !path_exists {
-            return Vec::new();
+    pub(crate) fn search_for_editor(search_path: &SearchPath) -> (Vec<String>, SearchPathReport) {
+        let mut report = SearchPathReport {
+            errors: search_path.pattern_errors(),
+            ..Default::default()
+        };
+        let path = search_path.resolved();
+        if let Err(e) = std::fs::metadata(&path) {
+            report
+                .errors
+                .push(format!("{}: {}", path.to_string_lossy(), e));
+            return (Vec::new(), report);
         }
 
-        let hashset: HashSet<String> = WalkDir::new(path)
-            .max_depth(2)
-            .into_iter()
-            .parallel_filter(|entry| entry.is_ok())
-            .parallel_map(|entry| entry.unwrap())
-            .parallel_filter(|entry| Configuration::is_unity_dir(&entry))
-            .parallel_map(|entry| entry.path().to_string_lossy().into())
-            .collect();
+        let mut found = Vec::new();
+        let mut walker = WalkDir::new(&path).max_depth(search_path.depth).into_iter();
+        while let Some(entry) = walker.next() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    report.errors.push(e.to_string());
+                    continue;
+                }
+            };
+            if !entry.file_type().is_dir() || !Configuration::is_unity_dir(entry.path()) {
+                continue;
+            }
+            // There is nothing more to find inside of an editor installation.
+            walker.skip_current_dir();
+            if search_path.accepts(entry.path()) {
+                found.push(entry.path().to_string_lossy().into());
+            }
+        }
+        report.editors = found.len();
 
-        Vec::from_iter(hashset)
+        (found, report)
     }
 }
 
-impl Default for Configuration {
-    fn default() -> Self {
-        let mut default = Self {
+impl Configuration {
+    /// Default settings before looking for editors, `Default` also runs `rebuild`.
+    pub fn unscanned() -> Self {
+        Self {
             #[cfg(windows)]
-            unity_search_paths: vec!["C:\\Program Files\\Unity\\Hub\\Editor".to_string()],
+            unity_search_paths: vec!["%ProgramFiles%\\Unity\\Hub\\Editor".into()],
             #[cfg(target_os = "macos")]
             unity_search_paths: vec![
-                "/Applications/Unity/Hub/Editor".to_string(),
-                "/Applications/Unity/".to_string(),
+                "/Applications/Unity/Hub/Editor".into(),
+                "/Applications/Unity/".into(),
             ],
             #[cfg(target_os = "linux")]
-            unity_search_paths: vec!["~/Unity/Hub/Editor".to_string()],
+            unity_search_paths: vec!["~/Unity/Hub/Editor".into()],
             editors_configurations: Vec::new(),
-        };
+            auto_discovery: true,
+            editor_executables: Vec::new(),
+            release_catalog_url: default_release_catalog_url(),
+            download_mirror: String::new(),
+            project_roots: Vec::new(),
+            project_scan: ScanOptions::default(),
+            ide_command: default_ide_command(),
+            ignored_projects: Vec::new(),
+            search_reports: HashMap::new(),
+            discovery_reports: Vec::new(),
+            project_root_reports: HashMap::new(),
+        }
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        let mut default = Configuration::unscanned();
         default.rebuild();
 
         default
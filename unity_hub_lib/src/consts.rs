@@ -1,24 +1,80 @@
 #[cfg(windows)]
-pub const UNITY_EXE_NAME: &str = "Unity.exe";
+pub const FILE_MANAGER: &str = "explorer";
 #[cfg(target_os = "macos")]
-pub const UNITY_EXE_NAME: &str = "Unity.app/Contents/MacOS/Unity";
+pub const FILE_MANAGER: &str = "open";
 #[cfg(target_os = "linux")]
-pub const UNITY_EXE_NAME: &str = "Unity";
+pub const FILE_MANAGER: &str = "xdg-open";
 
+/// Directories where editors usually end up without being in any configured search path.
 #[cfg(windows)]
-pub const SLASH: &str = "\\";
-#[cfg(unix)]
-pub const SLASH: &str = "/";
+pub const KNOWN_EDITOR_LOCATIONS: &[&str] = &[
+    "%ProgramFiles%\\Unity\\Hub\\Editor",
+    "%ProgramFiles%\\Unity",
+    "%ProgramFiles(x86)%\\Unity",
+];
+#[cfg(target_os = "macos")]
+pub const KNOWN_EDITOR_LOCATIONS: &[&str] = &[
+    "/Applications/Unity/Hub/Editor",
+    "/Applications/Unity",
+    "~/Applications/Unity/Hub/Editor",
+];
+#[cfg(target_os = "linux")]
+pub const KNOWN_EDITOR_LOCATIONS: &[&str] = &[
+    "~/Unity/Hub/Editor",
+    "/opt/unity",
+    "/opt/Unity",
+    "/opt/unityhub/Editor",
+    "~/.var/app/com.unity.UnityHub/home/Unity/Hub/Editor",
+];
+
 #[cfg(windows)]
-pub const FILE_MANAGER: &str = "explorer";
+pub const HUB_CONFIG_DIRS: &[&str] = &["%APPDATA%\\UnityHub"];
 #[cfg(target_os = "macos")]
-pub const FILE_MANAGER: &str = "open";
+pub const HUB_CONFIG_DIRS: &[&str] = &["~/Library/Application Support/UnityHub"];
 #[cfg(target_os = "linux")]
-pub const FILE_MANAGER: &str = "xdg-open";
+pub const HUB_CONFIG_DIRS: &[&str] = &[
+    "~/.config/UnityHub",
+    "~/.var/app/com.unity.UnityHub/config/UnityHub",
+];
+
+/// Environment variables commonly used by build scripts to point at an editor.
+pub const EDITOR_ENV_VARS: &[&str] = &[
+    "UNITY_PATH",
+    "UNITY_EDITOR",
+    "UNITY_EDITOR_PATH",
+    "UNITY_EXE",
+];
+
+/// Unity's public editor release API, can be replaced with a mirror in the configuration.
+pub const RELEASE_CATALOG_URL: &str =
+    "https://services.api.unity.com/unity/editor/release/v1/releases";
 
+/// Log of the last editor session, Unity keeps the previous one as `Editor-prev.log` next to it.
 #[cfg(windows)]
-pub const TEMPLATES_DIR: &str = "Data\\Resources\\PackageManager\\ProjectTemplates";
+pub const EDITOR_LOG_PATH: &str = "%LOCALAPPDATA%\\Unity\\Editor\\Editor.log";
 #[cfg(target_os = "macos")]
-pub const TEMPLATES_DIR: &str = "Contents/Resources/PackageManager/ProjectTemplates";
+pub const EDITOR_LOG_PATH: &str = "~/Library/Logs/Unity/Editor.log";
 #[cfg(target_os = "linux")]
-pub const TEMPLATES_DIR: &str = "Data/Resources/PackageManager/ProjectTemplates";
+pub const EDITOR_LOG_PATH: &str = "~/.config/unity3d/Editor.log";
+
+/// Command opening a source file at a line, `{file}`, `{line}` and `{column}` are replaced.
+pub const DEFAULT_IDE_COMMAND: &str = "code --goto {file}:{line}:{column}";
+
+/// Folders the editor and the OS write crash reports to.
+#[cfg(windows)]
+pub const EDITOR_CRASH_DIRS: &[&str] = &["%TEMP%\\Unity\\Editor\\Crashes"];
+#[cfg(target_os = "macos")]
+pub const EDITOR_CRASH_DIRS: &[&str] = &["~/Library/Logs/DiagnosticReports"];
+#[cfg(target_os = "linux")]
+pub const EDITOR_CRASH_DIRS: &[&str] = &["~/.config/unity3d/Crashes", "/tmp/Unity/Editor/Crashes"];
+
+/// License file written by the editor or the Hub on activation.
+#[cfg(windows)]
+pub const UNITY_LICENSE_PATH: &str = "%PROGRAMDATA%\\Unity\\Unity_lic.ulf";
+#[cfg(target_os = "macos")]
+pub const UNITY_LICENSE_PATH: &str = "/Library/Application Support/Unity/Unity_lic.ulf";
+#[cfg(target_os = "linux")]
+pub const UNITY_LICENSE_PATH: &str = "~/.local/share/unity3d/Unity/Unity_lic.ulf";
+
+/// Days before the license expiry from which it's reported as expiring.
+pub const LICENSE_EXPIRY_WARNING_DAYS: u64 = 14;
@@ -0,0 +1,183 @@
+use crate::{
+    config::Configuration,
+    consts,
+    editor_layout::EditorLayout,
+    paths,
+    search_path::{SearchPath, SearchPathReport},
+};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiscoverySource {
+    KnownLocations,
+    UnityHub,
+    SystemPath,
+    Environment,
+    Manual,
+}
+
+impl DiscoverySource {
+    pub const AUTOMATIC: [DiscoverySource; 4] = [
+        DiscoverySource::KnownLocations,
+        DiscoverySource::UnityHub,
+        DiscoverySource::SystemPath,
+        DiscoverySource::Environment,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DiscoverySource::KnownLocations => "Well-known locations",
+            DiscoverySource::UnityHub => "Unity Hub",
+            DiscoverySource::SystemPath => "PATH",
+            DiscoverySource::Environment => "Environment variables",
+            DiscoverySource::Manual => "Registered executables",
+        }
+    }
+
+    /// Returns editor directories found by this source.
+    pub fn discover(&self, config: &Configuration) -> (Vec<PathBuf>, SearchPathReport) {
+        let mut report = SearchPathReport::default();
+        let mut found = Vec::new();
+
+        match self {
+            DiscoverySource::KnownLocations => {
+                for location in consts::KNOWN_EDITOR_LOCATIONS {
+                    search_existing(&SearchPath::new(location), &mut found, &mut report);
+                }
+            }
+            DiscoverySource::UnityHub => {
+                for dir in consts::HUB_CONFIG_DIRS.iter().map(|dir| paths::expand(dir)) {
+                    if !dir.is_dir() {
+                        continue;
+                    }
+                    if let Some(secondary) = read_secondary_install_path(&dir) {
+                        search_existing(&SearchPath::new(&secondary), &mut found, &mut report);
+                    }
+                    for file in ["editors-v2.json", "editors.json"] {
+                        let Ok(content) = std::fs::read_to_string(dir.join(file)) else {
+                            continue;
+                        };
+                        match parse_hub_editors(&content) {
+                            Ok(locations) => add_locations(locations, &mut found, &mut report),
+                            Err(e) => report.errors.push(format!("{}: {}", file, e)),
+                        }
+                    }
+                }
+            }
+            DiscoverySource::SystemPath => {
+                let exe_name = Path::new(EditorLayout::current().exe_name())
+                    .file_name()
+                    .unwrap_or_default();
+                if let Some(path_var) = std::env::var_os("PATH") {
+                    let locations = std::env::split_paths(&path_var)
+                        .map(|dir| dir.join(exe_name))
+                        .filter(|exe| exe.is_file());
+                    add_locations(locations, &mut found, &mut report);
+                }
+            }
+            DiscoverySource::Environment => {
+                let locations = consts::EDITOR_ENV_VARS
+                    .iter()
+                    .filter_map(std::env::var_os)
+                    .filter(|value| !value.is_empty())
+                    .map(PathBuf::from);
+                add_locations(locations, &mut found, &mut report);
+            }
+            DiscoverySource::Manual => {
+                for exe in &config.editor_executables {
+                    match editor_dir_from_exe(&paths::expand(exe)) {
+                        Some(dir) => found.push(dir),
+                        None => report.errors.push(format!("{} is not a Unity editor", exe)),
+                    }
+                }
+            }
+        }
+        report.editors = found.len();
+
+        (found, report)
+    }
+}
+
+/// Resolves editor directory from an executable, `Unity.app` bundle or editor directory path.
+pub fn editor_dir_from_exe(path: &Path) -> Option<PathBuf> {
+    let path = std::fs::canonicalize(path)
+        .ok()
+        .filter(|resolved| !resolved.to_string_lossy().starts_with(r"\\?\"))
+        .unwrap_or_else(|| path.to_path_buf());
+    let dir = if path.extension().is_some_and(|ext| ext == "app") {
+        path.parent()?.to_path_buf()
+    } else if path.is_file() {
+        EditorLayout::ALL
+            .iter()
+            .find_map(|layout| layout.base_path_from_exe(&path))?
+    } else {
+        path
+    };
+
+    Configuration::is_unity_dir(&dir).then_some(dir)
+}
+
+/// Reads the editor install location configured in Unity Hub preferences.
+pub fn read_secondary_install_path(hub_config_dir: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(hub_config_dir.join("secondaryInstallPath.json")).ok()?;
+    let path: String = serde_json::from_str(&content).ok()?;
+    (!path.trim().is_empty()).then_some(path)
+}
+
+/// Extracts editor locations from Unity Hub `editors-v2.json` or legacy `editors.json` content.
+pub fn parse_hub_editors(content: &str) -> Result<Vec<PathBuf>, serde_json::Error> {
+    let root: Value = serde_json::from_str(content)?;
+    let entries: Vec<&Value> = match root.get("data") {
+        Some(Value::Array(data)) => data.iter().collect(),
+        _ => root
+            .as_object()
+            .map(|map| map.values().collect())
+            .unwrap_or_default(),
+    };
+
+    let locations = entries
+        .into_iter()
+        .filter_map(|entry| entry.get("location"))
+        .flat_map(|location| match location {
+            Value::String(path) => vec![path.as_str()],
+            Value::Array(paths) => paths.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        })
+        .map(PathBuf::from)
+        .collect();
+
+    Ok(locations)
+}
+
+fn search_existing(
+    search_path: &SearchPath,
+    found: &mut Vec<PathBuf>,
+    report: &mut SearchPathReport,
+) {
+    if !search_path.resolved().is_dir() {
+        return;
+    }
+    let (editors, search_report) = Configuration::search_for_editor(search_path);
+    found.extend(editors.into_iter().map(PathBuf::from));
+    report.errors.extend(search_report.errors);
+}
+
+fn add_locations(
+    locations: impl IntoIterator<Item = PathBuf>,
+    found: &mut Vec<PathBuf>,
+    report: &mut SearchPathReport,
+) {
+    for location in locations {
+        match editor_dir_from_exe(&location) {
+            Some(dir) => found.push(dir),
+            None if location.exists() => report.errors.push(format!(
+                "{} is not a Unity editor",
+                location.to_string_lossy()
+            )),
+            None => report
+                .errors
+                .push(format!("{} does not exist", location.to_string_lossy())),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Directory structure of an editor installation, independent from the OS the hub runs on.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditorLayout {
+    Windows,
+    MacOs,
+    Linux,
+}
+
+impl Default for EditorLayout {
+    fn default() -> Self {
+        EditorLayout::current()
+    }
+}
+
+impl EditorLayout {
+    pub const ALL: [EditorLayout; 3] = [
+        EditorLayout::Windows,
+        EditorLayout::MacOs,
+        EditorLayout::Linux,
+    ];
+
+    /// Layout of editors installed on the OS the hub was built for.
+    pub fn current() -> Self {
+        if cfg!(windows) {
+            EditorLayout::Windows
+        } else if cfg!(target_os = "macos") {
+            EditorLayout::MacOs
+        } else {
+            EditorLayout::Linux
+        }
+    }
+
+    /// Recognizes layout of the editor installed at `base_path`.
+    pub fn detect(base_path: &Path) -> Option<Self> {
+        EditorLayout::ALL
+            .into_iter()
+            .find(|layout| layout.is_editor_dir(base_path))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EditorLayout::Windows => "Windows",
+            EditorLayout::MacOs => "macOS",
+            EditorLayout::Linux => "Linux",
+        }
+    }
+
+    /// Executable path relative to the editor directory, always with `/` separators.
+    pub fn exe_name(&self) -> &'static str {
+        match self {
+            EditorLayout::Windows => "Unity.exe",
+            EditorLayout::MacOs => "Unity.app/Contents/MacOS/Unity",
+            EditorLayout::Linux => "Unity",
+        }
+    }
+
+    fn data_dir(&self) -> &'static str {
+        match self {
+            EditorLayout::Windows | EditorLayout::Linux => "Data",
+            EditorLayout::MacOs => "Unity.app/Contents",
+        }
+    }
+
+    pub fn is_editor_dir(&self, base_path: &Path) -> bool {
+        let exe_exists = self.exe_path(base_path).is_file();
+        match self {
+            // Uninstaller is missing in editors unpacked from archives, Data folder is always there.
+            EditorLayout::Windows => {
+                exe_exists
+                    && (base_path.join("Uninstall.exe").exists()
+                        || base_path.join(self.data_dir()).is_dir())
+            }
+            EditorLayout::MacOs => exe_exists,
+            EditorLayout::Linux => exe_exists && base_path.join(self.data_dir()).is_dir(),
+        }
+    }
+
+    pub fn exe_path(&self, base_path: &Path) -> PathBuf {
+        join(base_path, self.exe_name())
+    }
+
+    /// Editor directory for a path to the executable of this layout.
+    pub fn base_path_from_exe(&self, exe_path: &Path) -> Option<PathBuf> {
+        let relative = Path::new(self.exe_name());
+        if !exe_path.ends_with(relative) {
+            return None;
+        }
+        exe_path
+            .ancestors()
+            .nth(relative.components().count())
+            .map(Path::to_path_buf)
+    }
+
+    pub fn templates_dir(&self, base_path: &Path) -> PathBuf {
+        join(
+            &join(base_path, self.data_dir()),
+            "Resources/PackageManager/ProjectTemplates",
+        )
+    }
+
+    /// Directory with build support modules, on macOS it lives next to `Unity.app`.
+    pub fn playback_engines_dir(&self, base_path: &Path) -> PathBuf {
+        match self {
+            EditorLayout::Windows | EditorLayout::Linux => join(base_path, "Data/PlaybackEngines"),
+            EditorLayout::MacOs => base_path.join("PlaybackEngines"),
+        }
+    }
+
+    pub fn info_plist(&self, base_path: &Path) -> Option<PathBuf> {
+        match self {
+            EditorLayout::MacOs => Some(join(base_path, "Unity.app/Contents/Info.plist")),
+            _ => None,
+        }
+    }
+}
+
+/// Joins `/` separated relative path so the result uses separators of the host.
+fn join(base: &Path, relative: &str) -> PathBuf {
+    relative
+        .split('/')
+        .fold(base.to_path_buf(), |path, part| path.join(part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/layouts");
+
+    #[test]
+    fn detects_layout_of_every_os() {
+        let fixtures = Path::new(FIXTURES);
+        assert_eq!(
+            EditorLayout::detect(&fixtures.join("windows")),
+            Some(EditorLayout::Windows)
+        );
+        assert_eq!(
+            EditorLayout::detect(&fixtures.join("macos")),
+            Some(EditorLayout::MacOs)
+        );
+        assert_eq!(
+            EditorLayout::detect(&fixtures.join("linux")),
+            Some(EditorLayout::Linux)
+        );
+        assert_eq!(EditorLayout::detect(&fixtures.join("empty")), None);
+    }
+
+    #[test]
+    fn exe_name_resolves_inside_the_editor_dir() {
+        let base = Path::new("editors").join("2022.3.10f1");
+        assert_eq!(EditorLayout::Windows.exe_name(), "Unity.exe");
+        assert_eq!(
+            EditorLayout::MacOs.exe_path(&base),
+            base.join("Unity.app")
+                .join("Contents")
+                .join("MacOS")
+                .join("Unity")
+        );
+        assert_eq!(EditorLayout::Linux.exe_path(&base), base.join("Unity"));
+    }
+
+    #[test]
+    fn base_path_from_exe_strips_the_exe_name() {
+        let base = Path::new("editors").join("2022.3.10f1");
+        for layout in EditorLayout::ALL {
+            assert_eq!(
+                layout.base_path_from_exe(&layout.exe_path(&base)),
+                Some(base.clone())
+            );
+        }
+        assert_eq!(
+            EditorLayout::Windows.base_path_from_exe(&base.join("Unity")),
+            None
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use crate::consts::EDITOR_LOG_PATH;
+use crate::paths;
+use crate::process;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogKind {
+    /// `Assets/Foo.cs(12,5): error CS0103: ...`
+    CompilerError,
+    CompilerWarning,
+    /// First line of an exception, e.g. `NullReferenceException: ...`.
+    Exception,
+    /// Frame of the stack trace following an exception.
+    StackFrame,
+    Error,
+    Warning,
+    /// Asset import and refresh messages.
+    Import,
+    Info,
+}
+
+/// Source file and line a log line points at, the file is usually relative to the project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub text: String,
+    pub kind: LogKind,
+    pub location: Option<SourceLocation>,
+}
+
+/// Editor log of the current user, the file may not exist yet.
+pub fn editor_log_path() -> PathBuf {
+    paths::expand(EDITOR_LOG_PATH)
+}
+
+/// Log files of the project `Logs` folder, most recently written first.
+pub fn project_logs(project_path: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(project_path.join("Logs")) else {
+        return Vec::new();
+    };
+    let mut logs: Vec<_> = entries
+        .flatten()
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "log")
+        })
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect();
+    logs.sort_by_key(|(time, _)| std::cmp::Reverse(*time));
+    logs.into_iter().map(|(_, path)| path).collect()
+}
+
+/// Classifies log lines one by one, lines after an exception are stack frames until an empty
+/// line shows up.
+#[derive(Debug, Clone, Default)]
+pub struct LogClassifier {
+    in_stack_trace: bool,
+}
+
+impl LogClassifier {
+    pub fn classify(&mut self, text: &str) -> LogLine {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            self.in_stack_trace = false;
+            return line(text, LogKind::Info, None);
+        }
+        if self.in_stack_trace && is_stack_frame(trimmed) {
+            return line(text, LogKind::StackFrame, frame_location(trimmed));
+        }
+        self.in_stack_trace = false;
+
+        if let Some((kind, location)) = compiler_message(trimmed) {
+            return line(text, kind, Some(location));
+        }
+        if is_exception(trimmed) {
+            self.in_stack_trace = true;
+            return line(text, LogKind::Exception, None);
+        }
+        let lower = trimmed.to_lowercase();
+        let kind = if lower.starts_with("error") || lower.contains("[error]") {
+            LogKind::Error
+        } else if lower.starts_with("warning") || lower.contains("[warning]") {
+            LogKind::Warning
+        } else if is_import(trimmed) {
+            LogKind::Import
+        } else {
+            LogKind::Info
+        };
+        line(text, kind, None)
+    }
+}
+
+fn line(text: &str, kind: LogKind, location: Option<SourceLocation>) -> LogLine {
+    LogLine {
+        text: text.to_string(),
+        kind,
+        location,
+    }
+}
+
+/// `<file>(<line>,<column>): error|warning <code>: <message>`
+fn compiler_message(text: &str) -> Option<(LogKind, SourceLocation)> {
+    let (head, kind) = if let Some((head, _)) = text.split_once("): error ") {
+        (head, LogKind::CompilerError)
+    } else if let Some((head, _)) = text.split_once("): warning ") {
+        (head, LogKind::CompilerWarning)
+    } else {
+        return None;
+    };
+    let (file, position) = head.rsplit_once('(')?;
+    let (line, column) = match position.split_once(',') {
+        Some((line, column)) => (line, column.parse().ok()),
+        None => (position, None),
+    };
+    Some((
+        kind,
+        SourceLocation {
+            file: file.trim().to_string(),
+            line: line.parse().ok()?,
+            column,
+        },
+    ))
+}
+
+/// `System.NullReferenceException: ...` or a bare `NullReferenceException`.
+fn is_exception(text: &str) -> bool {
+    let name = text.split_once(':').map_or(text, |(name, _)| name);
+    name.ends_with("Exception") && !name.contains(' ')
+}
+
+/// Mono frames look like `Foo.Bar:Baz () (at Assets/Foo.cs:12)`, .NET ones like `at Foo.Bar()`.
+fn is_stack_frame(text: &str) -> bool {
+    text.starts_with("at ")
+        || text.contains("(at ")
+        || text
+            .split_once(" (")
+            .is_some_and(|(method, _)| method.contains(':') && !method.contains(' '))
+}
+
+fn frame_location(text: &str) -> Option<SourceLocation> {
+    let (_, rest) = text.split_once("(at ")?;
+    let location = rest.split_once(')').map_or(rest, |(location, _)| location);
+    let (file, line) = location.rsplit_once(':')?;
+    Some(SourceLocation {
+        file: file.to_string(),
+        line: line.parse().ok()?,
+        column: None,
+    })
+}
+
+fn is_import(text: &str) -> bool {
+    const PREFIXES: [&str; 6] = [
+        "Start importing",
+        "Done importing",
+        "Importing",
+        "Refreshing native plugins",
+        "Asset Pipeline Refresh",
+        "[Worker",
+    ];
+    PREFIXES.iter().any(|prefix| text.starts_with(prefix))
+}
+
+/// Runs `command` with `{file}`, `{line}` and `{column}` replaced, see `DEFAULT_IDE_COMMAND`.
+/// Relative files are resolved against `project_path`.
+pub fn open_in_ide(
+    command: &str,
+    project_path: &Path,
+    location: &SourceLocation,
+) -> std::io::Result<()> {
+    let file = project_path.join(&location.file);
+    let file = file.to_string_lossy();
+    let line = location.line.to_string();
+    let column = location.column.unwrap_or(1).to_string();
+    let mut parts = command.split_whitespace().map(|part| {
+        part.replace("{file}", &file)
+            .replace("{line}", &line)
+            .replace("{column}", &column)
+    });
+    let Some(program) = parts.next() else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "IDE command is empty",
+        ));
+    };
+    process::spawn_detached(Command::new(program).args(parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(file: &str, line: u32, column: Option<u32>) -> SourceLocation {
+        SourceLocation {
+            file: file.to_string(),
+            line,
+            column,
+        }
+    }
+
+    #[test]
+    fn reads_compiler_messages() {
+        assert_eq!(
+            compiler_message(
+                "Assets/Scripts/Player.cs(12,5): error CS0103: The name 'speed' does not exist"
+            ),
+            Some((
+                LogKind::CompilerError,
+                location("Assets/Scripts/Player.cs", 12, Some(5))
+            ))
+        );
+        assert_eq!(
+            compiler_message(
+                "Assets/Player.cs(40): warning CS0414: The field is assigned but never used"
+            ),
+            Some((
+                LogKind::CompilerWarning,
+                location("Assets/Player.cs", 40, None)
+            ))
+        );
+        assert_eq!(compiler_message("Compilation failed: 1 error(s)"), None);
+    }
+
+    #[test]
+    fn reads_compiler_messages_with_windows_paths() {
+        assert_eq!(
+            compiler_message(
+                r"C:\Projects\Game (old)\Assets\Player.cs(12,5): error CS1002: ; expected"
+            ),
+            Some((
+                LogKind::CompilerError,
+                location(r"C:\Projects\Game (old)\Assets\Player.cs", 12, Some(5))
+            ))
+        );
+    }
+
+    #[test]
+    fn reads_frame_locations() {
+        assert_eq!(
+            frame_location("Player.Update () (at Assets/Scripts/Player.cs:27)"),
+            Some(location("Assets/Scripts/Player.cs", 27, None))
+        );
+        assert_eq!(
+            frame_location(r"Player.Update () (at C:\Projects\Game\Assets\Player.cs:27)"),
+            Some(location(r"C:\Projects\Game\Assets\Player.cs", 27, None))
+        );
+        assert_eq!(
+            frame_location("UnityEngine.Debug:Log (object) (at <8f3a3ad5d0e24d4c>:0)"),
+            Some(location("<8f3a3ad5d0e24d4c>", 0, None))
+        );
+        assert_eq!(frame_location("at Player.Update ()"), None);
+    }
+
+    #[test]
+    fn stack_frames_follow_exceptions_until_an_empty_line() {
+        let mut classifier = LogClassifier::default();
+        let kinds: Vec<LogKind> = [
+            "NullReferenceException: Object reference not set to an instance of an object",
+            "Player.Update () (at Assets/Scripts/Player.cs:27)",
+            "UnityEngine.Debug:Log (object)",
+            "",
+            "Player.Update () (at Assets/Scripts/Player.cs:27)",
+        ]
+        .into_iter()
+        .map(|text| classifier.classify(text).kind)
+        .collect();
+        assert_eq!(
+            kinds,
+            [
+                LogKind::Exception,
+                LogKind::StackFrame,
+                LogKind::StackFrame,
+                LogKind::Info,
+                LogKind::Info
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_other_lines() {
+        let mut classifier = LogClassifier::default();
+        let mut classify = |text: &str| classifier.classify(text);
+        let error = classify(r"C:\Game\Assets\Player.cs(12,5): error CS0103: missing");
+        assert_eq!(error.kind, LogKind::CompilerError);
+        assert_eq!(
+            error.location,
+            Some(location(r"C:\Game\Assets\Player.cs", 12, Some(5)))
+        );
+        assert_eq!(classify("Error building Player").kind, LogKind::Error);
+        assert_eq!(classify("[Warning] Shader fallback").kind, LogKind::Warning);
+        assert_eq!(
+            classify("Start importing Assets/Player.prefab").kind,
+            LogKind::Import
+        );
+        assert_eq!(classify("Loaded scene").kind, LogKind::Info);
+        // A sentence mentioning an exception isn't one.
+        assert_eq!(
+            classify("Caught an exception: NullReferenceException").kind,
+            LogKind::Info
+        );
+    }
+}
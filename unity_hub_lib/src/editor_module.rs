@@ -0,0 +1,163 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Single entry of the `modules.json` file shipped with every editor installed through Unity Hub.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorModule {
+    pub id: String,
+    pub name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub description: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub category: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub visible: bool,
+    /// Hub marks installed modules as `selected`, newer versions also write `isInstalled`.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub selected: bool,
+    #[serde(default)]
+    pub is_installed: Option<bool>,
+    #[serde(default)]
+    pub download_url: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub download_size: u64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub installed_size: u64,
+    #[serde(default)]
+    pub checksum: Option<String>,
+    /// Install location, usually starting with `{UNITY_PATH}`.
+    #[serde(default)]
+    pub destination: Option<String>,
+    /// Id of the module this one is a part of, e.g. Android SDK belongs to `android`.
+    #[serde(default)]
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub extracted_path_rename: Option<PathRename>,
+}
+
+/// Hub writes `null` for values it doesn't know, e.g. the size of third party downloads.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Folder renamed after extracting a module, both paths start with `{UNITY_PATH}`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct PathRename {
+    pub from: String,
+    pub to: String,
+}
+
+impl EditorModule {
+    pub fn installed(&self) -> bool {
+        self.is_installed.unwrap_or(self.selected)
+    }
+
+    pub fn is_platform(&self) -> bool {
+        // `modules.json` uses `Platforms`, the release catalog `PLATFORM`.
+        self.parent.is_none()
+            && (self.category.eq_ignore_ascii_case("Platforms")
+                || self.category.eq_ignore_ascii_case("Platform"))
+    }
+
+    /// Short platform name, `Linux Build Support (IL2CPP)` becomes `Linux (IL2CPP)`.
+    pub fn platform_name(&self) -> String {
+        self.name
+            .replace(" Build Support", "")
+            .replace(" Support", "")
+            .trim()
+            .to_string()
+    }
+
+    pub fn sub_modules<'a>(
+        &'a self,
+        modules: &'a [EditorModule],
+    ) -> impl Iterator<Item = &'a EditorModule> {
+        modules
+            .iter()
+            .filter(move |module| module.parent.as_deref() == Some(self.id.as_str()))
+    }
+
+    /// Parses the content of a `modules.json` file.
+    pub fn parse(content: &str) -> Result<Vec<EditorModule>, serde_json::Error> {
+        serde_json::from_str(content)
+    }
+
+    /// Reads modules of the editor installed at `base_path`, empty when there is no `modules.json`.
+    pub fn read(base_path: &Path) -> Vec<EditorModule> {
+        find_modules_json(base_path)
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| EditorModule::parse(&content).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Unity Hub puts `modules.json` next to `Unity.app` on macOS and above `Editor` elsewhere.
+pub fn find_modules_json(base_path: &Path) -> Option<PathBuf> {
+    [Some(base_path), base_path.parent()]
+        .into_iter()
+        .flatten()
+        .map(|dir| dir.join("modules.json"))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/editor_module/modules.json"
+    );
+
+    fn modules() -> Vec<EditorModule> {
+        EditorModule::parse(&std::fs::read_to_string(FIXTURE).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parses_hub_modules_json() {
+        let modules = modules();
+        assert_eq!(modules.len(), 4);
+        let documentation = &modules[0];
+        assert_eq!(documentation.download_size, 357_870_375);
+        assert_eq!(documentation.checksum, None);
+        assert_eq!(
+            documentation.extracted_path_rename,
+            Some(PathRename {
+                from: "{UNITY_PATH}/Editor/Data/Documentation/Documentation".to_string(),
+                to: "{UNITY_PATH}/Editor/Data/Documentation".to_string(),
+            })
+        );
+        assert!(!documentation.installed());
+    }
+
+    #[test]
+    fn accepts_null_values() {
+        let tools = &modules()[2];
+        assert_eq!(tools.description, "");
+        assert_eq!(tools.category, "");
+        assert_eq!(tools.download_size, 0);
+        assert_eq!(tools.parent.as_deref(), Some("android"));
+        assert!(tools.installed());
+    }
+
+    #[test]
+    fn lists_installed_platforms_and_their_parts() {
+        let modules = modules();
+        let platforms: Vec<String> = modules
+            .iter()
+            .filter(|module| module.is_platform() && module.installed())
+            .map(EditorModule::platform_name)
+            .collect();
+        assert_eq!(platforms, ["Android"]);
+        let sub_modules: Vec<&str> = modules[1]
+            .sub_modules(&modules)
+            .map(|module| module.id.as_str())
+            .collect();
+        assert_eq!(sub_modules, ["android-sdk-ndk-tools"]);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::consts::EDITOR_CRASH_DIRS;
+use crate::log_tail::LogTail;
+use crate::paths;
+use crate::unity_editor::UnityEditor;
+use crate::unity_project::UnityProject;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Log lines kept while the editor runs and reported once it exits.
+const LOG_TAIL_LINES: usize = 50;
+
+/// Editor started by the hub with the project open.
+#[derive(Debug)]
+pub struct RunningEditor {
+    pub project_path: String,
+    pub title: String,
+    pub editor_version: String,
+    pub log_file: PathBuf,
+    started: Instant,
+    started_at: SystemTime,
+    child: Child,
+    tail: LogTail,
+    recent_log: VecDeque<String>,
+    killed: bool,
+}
+
+/// Editor that stopped, successfully or not.
+#[derive(Debug, Clone)]
+pub struct EditorExit {
+    pub project_path: String,
+    pub title: String,
+    pub editor_version: String,
+    /// `None` when the editor was killed by a signal or the status couldn't be read.
+    pub exit_code: Option<i32>,
+    /// Signal that ended the editor, only reported on Unix.
+    pub signal: Option<i32>,
+    pub duration: Duration,
+    /// Killed from the hub.
+    pub killed: bool,
+    pub log_file: PathBuf,
+    /// Last lines of the editor log.
+    pub log_tail: Vec<String>,
+    /// Crash reports written while the editor was running.
+    pub crash_reports: Vec<PathBuf>,
+}
+
+impl EditorExit {
+    /// Exited with an error or left a crash report behind, killing it doesn't count.
+    pub fn crashed(&self) -> bool {
+        !self.killed && (self.exit_code != Some(0) || !self.crash_reports.is_empty())
+    }
+
+    pub fn status(&self) -> String {
+        match (self.killed, self.exit_code, self.signal) {
+            (true, _, _) => "killed".to_string(),
+            (_, Some(code), _) => format!("exit code {}", code),
+            (_, None, Some(signal)) => format!("signal {}", signal),
+            (_, None, None) => "unknown status".to_string(),
+        }
+    }
+}
+
+impl RunningEditor {
+    /// Opens `project` in `editor`, the log goes to `Logs/Editor.log` of the project so editors
+    /// running side by side don't share one.
+    pub fn launch(editor: &UnityEditor, project: &UnityProject) -> std::io::Result<Self> {
+        let log_file = Path::new(&project.path).join("Logs").join("Editor.log");
+        if let Some(dir) = log_file.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        let _ = std::fs::remove_file(&log_file);
+        let child = Command::new(&editor.exe_path)
+            .arg("-projectpath")
+            .arg(&project.path)
+            .arg("-logFile")
+            .arg(&log_file)
+            .spawn()?;
+        Ok(Self {
+            project_path: project.path.clone(),
+            title: project.title.clone(),
+            editor_version: editor.version.clone(),
+            tail: LogTail::new(&log_file),
+            log_file,
+            started: Instant::now(),
+            started_at: SystemTime::now(),
+            child,
+            recent_log: VecDeque::new(),
+            killed: false,
+        })
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.child.id()
+    }
+
+    pub fn running_for(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    pub fn kill(&mut self) -> std::io::Result<()> {
+        self.killed = true;
+        self.child.kill()
+    }
+
+    /// Reads new log lines and returns the exit once the editor stopped.
+    pub fn poll(&mut self) -> Option<EditorExit> {
+        self.read_log(false);
+        match self.child.try_wait() {
+            Ok(Some(status)) => Some(self.exit(Some(status))),
+            Ok(None) => None,
+            // The process can't be waited for anymore, it's gone without a known status.
+            Err(_) => Some(self.exit(None)),
+        }
+    }
+
+    /// Blocks until the editor stopped.
+    pub fn wait(mut self) -> std::io::Result<EditorExit> {
+        let status = self.child.wait()?;
+        Ok(self.exit(Some(status)))
+    }
+
+    fn read_log(&mut self, remaining: bool) {
+        let lines = if remaining {
+            self.tail.read_remaining()
+        } else {
+            self.tail.read_lines()
+        };
+        for line in lines {
+            if self.recent_log.len() == LOG_TAIL_LINES {
+                self.recent_log.pop_front();
+            }
+            self.recent_log.push_back(line);
+        }
+    }
+
+    fn exit(&mut self, status: Option<ExitStatus>) -> EditorExit {
+        self.read_log(true);
+        EditorExit {
+            project_path: self.project_path.clone(),
+            title: self.title.clone(),
+            editor_version: self.editor_version.clone(),
+            exit_code: status.and_then(|status| status.code()),
+            signal: status.as_ref().and_then(signal),
+            duration: self.started.elapsed(),
+            killed: self.killed,
+            log_file: self.log_file.clone(),
+            log_tail: self.recent_log.iter().cloned().collect(),
+            crash_reports: crash_reports_since(self.started_at),
+        }
+    }
+}
+
+/// Editors launched by the hub, at most one per project.
+#[derive(Debug, Default)]
+pub struct EditorProcesses {
+    running: Vec<RunningEditor>,
+}
+
+impl EditorProcesses {
+    /// Fails when the project is already open, Unity would refuse the locked project anyway.
+    pub fn launch(&mut self, editor: &UnityEditor, project: &UnityProject) -> std::io::Result<()> {
+        if let Some(running) = self.get(&project.path) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!(
+                    "{} is already open in Unity {}",
+                    project.title, running.editor_version
+                ),
+            ));
+        }
+        let running = RunningEditor::launch(editor, project)?;
+        self.running.push(running);
+        Ok(())
+    }
+
+    pub fn get(&self, project_path: &str) -> Option<&RunningEditor> {
+        self.running
+            .iter()
+            .find(|running| running.project_path == project_path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RunningEditor> {
+        self.running.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.running.is_empty()
+    }
+
+    pub fn kill(&mut self, project_path: &str) -> std::io::Result<()> {
+        match self
+            .running
+            .iter_mut()
+            .find(|running| running.project_path == project_path)
+        {
+            Some(running) => running.kill(),
+            None => Ok(()),
+        }
+    }
+
+    /// Editors that stopped since the last call.
+    pub fn poll(&mut self) -> Vec<EditorExit> {
+        let mut exits = Vec::new();
+        self.running.retain_mut(|running| match running.poll() {
+            Some(exit) => {
+                exits.push(exit);
+                false
+            }
+            None => true,
+        });
+        exits
+    }
+}
+
+/// Crash report files and folders modified after `since`, newest first.
+pub fn crash_reports_since(since: SystemTime) -> Vec<PathBuf> {
+    let mut reports: Vec<(SystemTime, PathBuf)> = EDITOR_CRASH_DIRS
+        .iter()
+        .map(|dir| paths::expand(dir))
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten())
+        .filter(|entry| is_editor_report(&entry.path()))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .filter(|(modified, _)| *modified >= since)
+        .collect();
+    reports.sort_by_key(|(time, _)| std::cmp::Reverse(*time));
+    reports.into_iter().map(|(_, path)| path).collect()
+}
+
+/// The macOS report folder is shared with every other application.
+fn is_editor_report(path: &Path) -> bool {
+    cfg!(not(target_os = "macos"))
+        || path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("Unity"))
+}
+
+#[cfg(unix)]
+fn signal(status: &ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+#[cfg(not(unix))]
+fn signal(_status: &ExitStatus) -> Option<i32> {
+    None
+}
@@ -0,0 +1,248 @@
+use crate::editor_layout::EditorLayout;
+use crate::editor_module::find_modules_json;
+use serde_json::Value;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Longest version string looked for, e.g. `6000.0.23f1` or `2023.1.0b12`.
+const MAX_VERSION_LEN: usize = 16;
+
+/// Reads editor version from metadata shipped with the editor.
+/// `base_path` is the editor directory, the one containing the Unity executable.
+pub fn detect(base_path: &Path, layout: EditorLayout) -> Option<String> {
+    let read = |path: PathBuf| std::fs::read_to_string(path).ok();
+    let exe_path = layout.exe_path(base_path);
+
+    if layout == EditorLayout::Windows {
+        if let Some(version) = from_pe(&exe_path) {
+            return Some(version);
+        }
+    }
+
+    if let Some(version) = layout
+        .info_plist(base_path)
+        .and_then(read)
+        .and_then(|content| from_info_plist(&content))
+    {
+        return Some(version);
+    }
+
+    if let Some(version) = find_modules_json(base_path)
+        .and_then(read)
+        .and_then(|content| from_modules_json(&content))
+    {
+        return Some(version);
+    }
+
+    if let Ok(dir) = std::fs::read_dir(layout.playback_engines_dir(base_path)) {
+        for entry in dir.flatten() {
+            let ivy = entry.path().join("ivy.xml");
+            if let Some(version) = read(ivy).and_then(|content| from_ivy_xml(&content)) {
+                return Some(version);
+            }
+        }
+    }
+
+    std::fs::File::open(exe_path)
+        .ok()
+        .and_then(|file| from_binary(std::io::BufReader::new(file)))
+}
+
+/// Version from `ProductVersion` of the Windows executable resources.
+pub fn from_pe(exe_path: &Path) -> Option<String> {
+    use exe::pe::VecPE;
+    use exe::VSVersionInfo;
+
+    let image = VecPE::from_disk_file(exe_path).ok()?;
+    let version_info = VSVersionInfo::parse(&image).ok()?;
+    let string_file_info = version_info.string_file_info?;
+    let string_map = string_file_info.children.first()?.string_map().ok()?;
+    let product_version = string_map.get("ProductVersion")?;
+
+    // ProductVersion looks like `2021.3.5f1_40eb3a945986`.
+    product_version.split('_').next().map(str::to_string)
+}
+
+/// Version from `CFBundleVersion` of a macOS `Info.plist` file.
+pub fn from_info_plist(content: &str) -> Option<String> {
+    let key_end = content.find("<key>CFBundleVersion</key>")? + "<key>CFBundleVersion</key>".len();
+    let rest = &content[key_end..];
+    let value_start = rest.find("<string>")? + "<string>".len();
+    let value_end = rest[value_start..].find("</string>")? + value_start;
+    find_version(rest[value_start..value_end].trim().as_bytes())
+}
+
+/// Version from download URLs listed in Unity Hub's `modules.json`.
+pub fn from_modules_json(content: &str) -> Option<String> {
+    let modules: Value = serde_json::from_str(content).ok()?;
+    modules
+        .as_array()?
+        .iter()
+        .filter_map(|module| module.get("downloadUrl").and_then(Value::as_str))
+        .find_map(|url| find_version(url.as_bytes()))
+}
+
+/// Version from the `unityVersion` attribute of a playback engine `ivy.xml`.
+pub fn from_ivy_xml(content: &str) -> Option<String> {
+    let attribute = "unityVersion=\"";
+    let start = content.find(attribute)? + attribute.len();
+    let end = content[start..].find('"')? + start;
+    find_version(&content.as_bytes()[start..end])
+}
+
+/// Version embedded in the editor executable, the slowest of all sources.
+pub fn from_binary(mut reader: impl Read) -> Option<String> {
+    const CHUNK: usize = 1024 * 1024;
+    let mut buffer = vec![0u8; CHUNK + MAX_VERSION_LEN];
+    let mut carried = 0;
+
+    loop {
+        let read = reader.read(&mut buffer[carried..]).ok()?;
+        if read == 0 {
+            return None;
+        }
+        let filled = carried + read;
+        // Unity stamps the full version followed by the changeset, e.g. `2022.3.1f1_6f9f5b9d`.
+        let mut search = &buffer[..filled];
+        while let Some((start, len)) = find_version_span(search) {
+            let end = start + len;
+            if search.get(end) == Some(&b'_') {
+                return std::str::from_utf8(&search[start..end])
+                    .ok()
+                    .map(str::to_string);
+            }
+            search = &search[end..];
+        }
+        carried = filled.min(MAX_VERSION_LEN);
+        buffer.copy_within(filled - carried..filled, 0);
+    }
+}
+
+/// Finds the first Unity version like `2021.3.5f1`, `5.6.7p2` or `6000.0.23f1` in the given bytes.
+pub fn find_version(bytes: &[u8]) -> Option<String> {
+    let (start, len) = find_version_span(bytes)?;
+    std::str::from_utf8(&bytes[start..start + len])
+        .ok()
+        .map(str::to_string)
+}
+
+fn find_version_span(bytes: &[u8]) -> Option<(usize, usize)> {
+    (0..bytes.len()).find_map(|start| {
+        if start > 0 && (bytes[start - 1].is_ascii_digit() || bytes[start - 1] == b'.') {
+            return None;
+        }
+        version_len(&bytes[start..]).map(|len| (start, len))
+    })
+}
+
+fn version_len(bytes: &[u8]) -> Option<usize> {
+    let digits = |from: usize| {
+        bytes[from.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let major = digits(0);
+    if !(major == 1 || major == 4) {
+        return None;
+    }
+    let mut pos = major;
+    for _ in 0..2 {
+        if bytes.get(pos) != Some(&b'.') {
+            return None;
+        }
+        let part = digits(pos + 1);
+        if part == 0 || part > 3 {
+            return None;
+        }
+        pos += 1 + part;
+    }
+    if !matches!(
+        bytes.get(pos),
+        Some(b'a' | b'b' | b'f' | b'p' | b'c' | b'x')
+    ) {
+        return None;
+    }
+    let build = digits(pos + 1);
+    if build == 0 || build > 3 {
+        return None;
+    }
+    pos += 1 + build;
+    if bytes.get(pos).is_some_and(|b| b.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    Some(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/editor_version");
+
+    fn fixture(name: &str) -> String {
+        std::fs::read_to_string(Path::new(FIXTURES).join(name)).unwrap()
+    }
+
+    #[test]
+    fn reads_bundle_version_from_info_plist() {
+        assert_eq!(
+            from_info_plist(&fixture("Info.plist")).as_deref(),
+            Some("2022.3.10f1")
+        );
+        assert_eq!(from_info_plist("<dict></dict>"), None);
+    }
+
+    #[test]
+    fn reads_version_from_module_download_urls() {
+        assert_eq!(
+            from_modules_json(&fixture("modules.json")).as_deref(),
+            Some("2022.3.10f1")
+        );
+        assert_eq!(from_modules_json("{}"), None);
+    }
+
+    #[test]
+    fn reads_unity_version_attribute_from_ivy_xml() {
+        assert_eq!(
+            from_ivy_xml(&fixture("ivy.xml")).as_deref(),
+            Some("2022.3.10f1")
+        );
+    }
+
+    #[test]
+    fn reads_version_stamped_before_changeset_from_binary() {
+        let mut binary = vec![0u8; 3 * 1024 * 1024];
+        binary.extend_from_slice(b"2022.3.9 2022.3.10f1_ff3792e53c62\0");
+        assert_eq!(
+            from_binary(binary.as_slice()).as_deref(),
+            Some("2022.3.10f1")
+        );
+    }
+
+    #[test]
+    fn finds_versions_of_every_era() {
+        assert_eq!(find_version(b"Unity 5.6.7p2 ").as_deref(), Some("5.6.7p2"));
+        assert_eq!(
+            find_version(b"/Editor/2021.3.5f1/").as_deref(),
+            Some("2021.3.5f1")
+        );
+        assert_eq!(find_version(b"6000.0.23f1").as_deref(), Some("6000.0.23f1"));
+        assert_eq!(find_version(b"2023.1.0b12").as_deref(), Some("2023.1.0b12"));
+        assert_eq!(find_version(b"12021.3.5f1"), None);
+        assert_eq!(find_version(b"version 2.0"), None);
+    }
+
+    #[test]
+    fn version_len_stops_at_the_build_number() {
+        assert_eq!(version_len(b"2021.3.5f1"), Some(10));
+        assert_eq!(version_len(b"2021.3.5f1_abc"), Some(10));
+        assert_eq!(version_len(b"5.6.7p2 "), Some(7));
+        assert_eq!(version_len(b"2021.3.5"), None);
+        assert_eq!(version_len(b"2021.3.5f1234"), None);
+        assert_eq!(version_len(b"2021.3.5f1x"), None);
+        assert_eq!(version_len(b"21.3.5f1"), None);
+    }
+}
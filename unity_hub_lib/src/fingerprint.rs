@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Modification time and size of a file or directory, both empty when it doesn't exist.
+/// Directories change their modification time when entries are added or removed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct FileStamp {
+    pub path: PathBuf,
+    pub modified: Option<SystemTime>,
+    pub len: u64,
+}
+
+impl FileStamp {
+    pub fn of(path: &Path) -> Self {
+        let meta = std::fs::metadata(path).ok();
+        Self {
+            path: path.to_path_buf(),
+            modified: meta.as_ref().and_then(|meta| meta.modified().ok()),
+            len: meta.map_or(0, |meta| meta.len()),
+        }
+    }
+
+    pub fn is_current(&self) -> bool {
+        *self == FileStamp::of(&self.path)
+    }
+}
+
+/// Stamps of the files a cached editor or project was read from, used to skip parsing it again
+/// when none of them changed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct Fingerprint {
+    pub files: Vec<FileStamp>,
+}
+
+impl Fingerprint {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            files: paths.into_iter().map(|path| FileStamp::of(&path)).collect(),
+        }
+    }
+
+    /// False for empty fingerprints, e.g. loaded from a config written before they existed.
+    pub fn is_current(&self) -> bool {
+        !self.files.is_empty() && self.files.iter().all(FileStamp::is_current)
+    }
+}
@@ -1,9 +1,18 @@
-use crate::{config::Configuration, unity_editor::UnityEditor, unity_project::UnityProject};
-use dpc_pariter::IteratorExt;
+use crate::{
+    batch::BatchJob,
+    build_profile::{BuildError, BuildProfile},
+    config::Configuration,
+    editor_process::RunningEditor,
+    project_root::ProjectRootReport,
+    project_scan::{scan_projects, CancelToken, ScanEvent, ScanOptions, ScanReport},
+    uninstall::{self, UninstallError, UninstallProgress},
+    unity_editor::UnityEditor,
+    unity_project::UnityProject,
+    watcher::WatchEvent,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::{path::PathBuf, process::Command};
-use walkdir::WalkDir;
+use std::path::Path;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Hub {
@@ -16,15 +25,71 @@ impl Hub {
         Self { config, projects }
     }
 
+    /// Rescans editors and projects, skipping the ones that didn't change since the last scan.
     pub fn update_data(&mut self) {
-        self.config.rebuild();
-        self.update_projects_info();
+        self.update_data_until(false, &CancelToken::default(), |_| {});
+    }
+
+    /// Rescans and parses all editors and projects again, ignoring cached information.
+    pub fn force_update_data(&mut self) {
+        self.update_data_until(true, &CancelToken::default(), |_| {});
+    }
+
+    /// Same as `update_data` or `force_update_data`, stopping early once `cancel` is set.
+    /// The hub is then only partly updated. Scans of project roots report to `on_event`.
+    pub fn update_data_until(
+        &mut self,
+        force: bool,
+        cancel: &CancelToken,
+        mut on_event: impl FnMut(ScanEvent),
+    ) {
+        if force {
+            self.config.force_rebuild();
+        } else {
+            self.config.rebuild();
+        }
+        if cancel.is_cancelled() {
+            return;
+        }
+        self.scan_project_roots(cancel, &mut on_event);
+        if cancel.is_cancelled() {
+            return;
+        }
+        self.refresh_projects(force, cancel);
     }
 
     pub fn update_projects_info(&mut self) {
+        self.refresh_projects(false, &CancelToken::default());
+    }
+
+    /// Takes the projects of a hub copy that was updated in the background. Projects still in
+    /// the list only get the scanned information, projects removed since `scanned_from` was
+    /// taken stay removed.
+    pub fn merge_scanned_projects(
+        &mut self,
+        scanned: Vec<UnityProject>,
+        scanned_from: &HashSet<String>,
+    ) {
+        let ignored = &self.config.ignored_projects;
+        for project in scanned {
+            match self.projects.iter_mut().find(|p| p.path == project.path) {
+                Some(current) => current.merge_scanned(project),
+                None if !scanned_from.contains(&project.path)
+                    && !ignored.contains(&project.path) =>
+                {
+                    self.projects.push(project)
+                }
+                None => {}
+            }
+        }
+        self.projects
+            .sort_by_key(|p| std::cmp::Reverse(p.edit_time));
+    }
+
+    fn refresh_projects(&mut self, force: bool, cancel: &CancelToken) {
         let mut registry = UnityProject::get_projects_from_registry()
             .into_iter()
-            .filter(|p| !self.projects.contains(p))
+            .filter(|p| !self.projects.contains(p) && !self.is_ignored(p))
             .collect();
         self.projects.append(&mut registry);
         self.projects = self
@@ -34,64 +99,243 @@ impl Hub {
             .collect::<HashSet<UnityProject>>()
             .into_iter()
             .collect();
-        self.projects.iter_mut().for_each(|project| {
-            project.update_info();
-        });
-        self.projects.sort_by(|a, b| b.edit_time.cmp(&a.edit_time));
+        self.projects
+            .iter_mut()
+            .filter(|project| force || !project.is_up_to_date())
+            .take_while(|_| !cancel.is_cancelled())
+            .for_each(|project| {
+                project.update_info();
+            });
+        self.projects
+            .sort_by_key(|p| std::cmp::Reverse(p.edit_time));
     }
 
-    pub fn run_project_nr(&self, nr: usize) {
-        let project = self.projects[nr].clone();
-
-        if let Some(editor) = self.editor_for_project(&project) {
-            Hub::run_project(&editor, &project);
+    /// Editor with exactly the version of the project, `None` when the version is unknown.
+    pub fn editor_for_project(&self, project: &UnityProject) -> Option<UnityEditor> {
+        if project.version.is_empty() {
+            return None;
         }
+        self.config
+            .editors_configurations
+            .iter()
+            .find(|editor| editor.version == project.version)
+            .cloned()
     }
 
-    pub fn editor_for_project(&self, project: &UnityProject) -> Option<UnityEditor> {
-        let editor_option = self
+    /// Batch job building `project` with `profile` in the editor matching its version.
+    pub fn build_job(
+        &self,
+        project: &UnityProject,
+        profile: &BuildProfile,
+    ) -> Result<BatchJob, BuildError> {
+        let editor = self
+            .editor_for_project(project)
+            .ok_or_else(|| BuildError::MissingEditor(project.version.clone()))?;
+        profile.job(&editor, project)
+    }
+
+    /// Projects that would have no editor left after removing `editor`.
+    pub fn projects_using_editor(&self, editor: &UnityEditor) -> Vec<UnityProject> {
+        self.projects
+            .iter()
+            .filter(|project| self.editor_for_project(project).as_ref() == Some(editor))
+            .cloned()
+            .collect()
+    }
+
+    /// Deletes the editor installation from disk, refusing to touch anything that doesn't
+    /// look like an editor or is still running. Call `update_data` afterwards.
+    pub fn uninstall_editor(
+        editor: &UnityEditor,
+        progress: impl FnMut(UninstallProgress),
+    ) -> Result<(), UninstallError> {
+        uninstall::uninstall(editor, progress)
+    }
+
+    /// Opens `project` in `editor`, the returned handle reports when the editor exits.
+    pub fn run_project(
+        editor: &UnityEditor,
+        project: &UnityProject,
+    ) -> std::io::Result<RunningEditor> {
+        RunningEditor::launch(editor, project)
+    }
+
+    /// Adds projects found in `path`, the report counts already known projects as duplicates.
+    pub fn search_for_projects_at_path(&mut self, path: &Path) -> ScanReport {
+        self.search_for_projects_until(path, &CancelToken::default(), &mut |_| {})
+    }
+
+    fn search_for_projects_until(
+        &mut self,
+        path: &Path,
+        cancel: &CancelToken,
+        on_event: &mut impl FnMut(ScanEvent),
+    ) -> ScanReport {
+        let mut new_projects = Vec::new();
+        let options = &self.config.project_scan;
+        let mut report = scan_projects(path, options, cancel, |event| {
+            if let ScanEvent::Found(project) = &event {
+                new_projects.push(project.clone());
+            }
+            on_event(event);
+        });
+
+        let known = new_projects
+            .iter()
+            .filter(|project| self.projects.contains(project) || self.is_ignored(project))
+            .count();
+        report.found -= known;
+        report.duplicates += known;
+        self.add_projects(new_projects);
+
+        report
+    }
+
+    /// Forgets the project and keeps it out of later scans, until it is relinked.
+    pub fn remove_project(&mut self, path: &str) {
+        self.projects.retain(|project| project.path != path);
+        if !self
             .config
-            .editors_configurations
-            .clone()
-            .into_iter()
-            .find(|editor| editor.version.contains(&project.version));
+            .ignored_projects
+            .iter()
+            .any(|ignored| ignored == path)
+        {
+            self.config.ignored_projects.push(path.to_string());
+        }
+    }
 
-        editor_option
+    /// Removed with `remove_project`.
+    pub fn is_ignored(&self, project: &UnityProject) -> bool {
+        self.config.ignored_projects.contains(&project.path)
     }
 
-    pub fn run_project(editor: &UnityEditor, project: &UnityProject) {
-        println!("{} -projectpath {}", editor.exe_path, project.path);
-        Command::new(&editor.exe_path)
-            .arg("-projectpath")
-            .arg(&project.path)
-            .spawn()
-            .expect("Failed to run project");
+    pub fn set_project_hidden(&mut self, path: &str, hidden: bool) {
+        if let Some(project) = self.projects.iter_mut().find(|p| p.path == path) {
+            project.hidden = hidden;
+        }
     }
 
-    pub fn search_for_projects_at_path(&mut self, path: &PathBuf) -> usize {
-        let path_exists = std::fs::metadata(path).is_ok();
-        if !path_exists {
-            return 0;
+    /// Projects in `root` that look like `project` after it was moved, best matches first.
+    /// Walks the whole folder, so run it on a worker thread.
+    pub fn find_moved_project(
+        project: &UnityProject,
+        root: &Path,
+        options: &ScanOptions,
+        cancel: &CancelToken,
+    ) -> Vec<UnityProject> {
+        let mut candidates = Vec::new();
+        scan_projects(root, options, cancel, |event| {
+            if let ScanEvent::Found(found) = event {
+                let score = project.moved_match_score(&found);
+                if score > 0 && found.path != project.path {
+                    candidates.push((score, found));
+                }
+            }
+        });
+        candidates.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        candidates.into_iter().map(|(_, found)| found).collect()
+    }
+
+    /// Points the project at `new_path`, keeping its title and hidden state. A separate entry
+    /// already listed for `new_path` is dropped. Returns false when there is no project there.
+    pub fn relink_project(&mut self, old_path: &str, new_path: &str) -> bool {
+        let Some(found) = UnityProject::try_get_project_at_path(new_path) else {
+            return false;
+        };
+        if !self.projects.iter().any(|p| p.path == old_path) {
+            return false;
         }
-        let projects = self.projects.clone();
-        let new_projects: Vec<UnityProject> = WalkDir::new(path)
-            .max_depth(3)
-            .into_iter()
-            .parallel_filter(|entry| entry.is_ok())
-            .parallel_map(|entry| {
-                UnityProject::try_get_project_at_path(
-                    &entry.unwrap().path().as_os_str().to_str().unwrap(),
-                )
-            })
-            .parallel_filter(|project| project.is_some())
-            .parallel_map(|project| project.unwrap())
-            .parallel_filter(move |p| !projects.contains(p))
-            .collect();
+        self.projects.retain(|p| p.path != found.path);
+        self.config
+            .ignored_projects
+            .retain(|ignored| *ignored != found.path);
+        if let Some(project) = self.projects.iter_mut().find(|p| p.path == old_path) {
+            project.path = found.path;
+            project.update_info();
+        }
+        self.projects
+            .sort_by_key(|p| std::cmp::Reverse(p.edit_time));
+        true
+    }
 
-        let len = new_projects.len();
-        self.projects.extend(new_projects);
+    /// Looks for new projects in enabled project roots and marks the ones that disappeared
+    /// from them as missing.
+    pub fn scan_project_roots(
+        &mut self,
+        cancel: &CancelToken,
+        on_event: &mut impl FnMut(ScanEvent),
+    ) {
+        self.config.project_root_reports.clear();
+        for root in self.config.project_roots.clone() {
+            if cancel.is_cancelled() {
+                return;
+            }
+            if !root.enabled {
+                continue;
+            }
+            let path = root.resolved();
+            let mut report = ProjectRootReport {
+                errors: self
+                    .search_for_projects_until(&path, cancel, on_event)
+                    .errors,
+                ..Default::default()
+            };
+            for project in self
+                .projects
+                .iter_mut()
+                .filter(|project| Path::new(&project.path).starts_with(&path))
+            {
+                if !project.is_up_to_date() {
+                    project.update_info();
+                }
+                if project.is_valid {
+                    report.projects += 1;
+                } else {
+                    report.missing += 1;
+                }
+            }
+            self.config.project_root_reports.insert(root.path, report);
+        }
+    }
 
-        len
+    /// Updates projects after `HubWatcher` reported a change, returns true when anything
+    /// changed. Search path changes need `update_data` and new projects in a changed project
+    /// root need a scan of it, both are left to the caller.
+    pub fn apply_watch_event(&mut self, event: &WatchEvent) -> bool {
+        match event {
+            WatchEvent::SearchPathChanged(_) => false,
+            WatchEvent::ProjectRootChanged(root) => {
+                let mut changed = false;
+                // Removed projects are kept, but shown as missing.
+                for project in self.projects.iter_mut() {
+                    if Path::new(&project.path).starts_with(root) && !project.is_up_to_date() {
+                        project.update_info();
+                        changed = true;
+                    }
+                }
+                changed
+            }
+            WatchEvent::ProjectChanged(path) => {
+                let Some(project) = self.projects.iter_mut().find(|p| &p.path == path) else {
+                    return false;
+                };
+                project.update_info();
+                self.projects
+                    .sort_by_key(|p| std::cmp::Reverse(p.edit_time));
+                true
+            }
+        }
+    }
+
+    /// Adds projects that are not known yet, keeping the most recently edited first.
+    pub fn add_projects(&mut self, projects: impl IntoIterator<Item = UnityProject>) {
+        for project in projects {
+            if !self.projects.contains(&project) && !self.is_ignored(&project) {
+                self.projects.push(project);
+            }
+        }
+        self.projects
+            .sort_by_key(|p| std::cmp::Reverse(p.edit_time));
     }
 }
 impl Default for Hub {
@@ -0,0 +1,653 @@
+use crate::config::Configuration;
+use crate::editor_layout::EditorLayout;
+use crate::editor_version;
+use base64::Engine;
+use sha2::Digest;
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use walkdir::WalkDir;
+
+const BUFFER_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug)]
+pub enum InstallError {
+    Io(PathBuf, std::io::Error),
+    Download(String, ureq::Error),
+    ChecksumMismatch {
+        expected: String,
+        actual: String,
+    },
+    UnsupportedChecksum(String),
+    /// Archive type can't be installed on this OS.
+    UnsupportedArchive(PathBuf),
+    Command(String),
+    /// Extracted files don't contain an editor.
+    NotAnEditor(PathBuf),
+    AlreadyInstalled(PathBuf),
+    /// Module has no download URL or install location.
+    NotInstallable(String),
+}
+
+impl fmt::Display for InstallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstallError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            InstallError::Download(url, err) => write!(f, "Failed to download {}: {}", url, err),
+            InstallError::ChecksumMismatch { expected, actual } => {
+                write!(f, "Checksum mismatch, expected {} got {}", expected, actual)
+            }
+            InstallError::UnsupportedChecksum(checksum) => {
+                write!(f, "Unsupported checksum format: {}", checksum)
+            }
+            InstallError::UnsupportedArchive(path) => {
+                write!(f, "{} can't be installed on this system", path.display())
+            }
+            InstallError::Command(message) => write!(f, "{}", message),
+            InstallError::NotAnEditor(path) => {
+                write!(f, "No Unity editor found in {}", path.display())
+            }
+            InstallError::AlreadyInstalled(path) => {
+                write!(f, "{} already exists", path.display())
+            }
+            InstallError::NotInstallable(name) => write!(f, "{} can't be installed", name),
+        }
+    }
+}
+
+impl std::error::Error for InstallError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallStage {
+    Downloading,
+    Verifying,
+    Extracting,
+    Finishing,
+}
+
+impl InstallStage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InstallStage::Downloading => "Downloading",
+            InstallStage::Verifying => "Verifying checksum",
+            InstallStage::Extracting => "Extracting",
+            InstallStage::Finishing => "Finishing",
+        }
+    }
+}
+
+/// Progress of the current stage, `total` is zero when it isn't known.
+#[derive(Debug, Clone, Copy)]
+pub struct InstallProgress {
+    pub stage: InstallStage,
+    pub done: u64,
+    pub total: u64,
+}
+
+impl InstallProgress {
+    fn new(stage: InstallStage, done: u64, total: u64) -> Self {
+        Self { stage, done, total }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    TarXz,
+    /// Used for Android SDK, NDK and OpenJDK modules.
+    Zip,
+    Pkg,
+    Dmg,
+    /// NSIS installer used for Windows editors and modules.
+    Exe,
+}
+
+impl ArchiveKind {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".tar.xz") {
+            Some(ArchiveKind::TarXz)
+        } else if name.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else if name.ends_with(".pkg") {
+            Some(ArchiveKind::Pkg)
+        } else if name.ends_with(".dmg") {
+            Some(ArchiveKind::Dmg)
+        } else if name.ends_with(".exe") {
+            Some(ArchiveKind::Exe)
+        } else {
+            None
+        }
+    }
+
+    /// Pkg and dmg need macOS tools, installers only run on Windows.
+    pub fn supported_on_host(&self) -> bool {
+        match self {
+            ArchiveKind::TarXz | ArchiveKind::Zip => true,
+            ArchiveKind::Pkg | ArchiveKind::Dmg => cfg!(target_os = "macos"),
+            ArchiveKind::Exe => cfg!(windows),
+        }
+    }
+}
+
+/// Where the archive comes from, a file picked from disk or a download URL.
+#[derive(Debug, Clone)]
+pub enum ArchiveSource {
+    File(PathBuf),
+    Url(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallRequest {
+    pub source: ArchiveSource,
+    /// MD5 or SHA-256 as hex, or a subresource integrity string like `sha384-...`.
+    pub checksum: Option<String>,
+    /// Search path the editor is installed into, as `<destination>/<version>`.
+    pub destination: PathBuf,
+    /// Replaces the host of download URLs, either another URL or a directory on a file share.
+    pub mirror: Option<String>,
+    /// Version being installed when known up front, it names the staging directory.
+    pub version: Option<String>,
+}
+
+/// URL or local path of `url` on the mirror, keeping the path part of the original URL.
+pub fn resolve_url(url: &str, mirror: Option<&str>) -> String {
+    let Some(mirror) = mirror.map(str::trim).filter(|mirror| !mirror.is_empty()) else {
+        return url.to_string();
+    };
+    let path = url
+        .split_once("://")
+        .and_then(|(_, rest)| rest.split_once('/'))
+        .map_or("", |(_, path)| path);
+    format!("{}/{}", mirror.trim_end_matches(['/', '\\']), path)
+}
+
+/// File name of the archive at `url`, without the query and fragment of signed download links.
+fn archive_name(url: &str) -> &str {
+    url.split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit('/').next())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("download")
+}
+
+/// Gets the archive to `dir`, mirrors on the file system are used in place without copying.
+pub fn fetch_archive(
+    url: &str,
+    mirror: Option<&str>,
+    dir: &Path,
+    progress: &mut impl FnMut(InstallProgress),
+) -> Result<PathBuf, InstallError> {
+    let url = resolve_url(url, mirror);
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        let path = PathBuf::from(url.strip_prefix("file://").unwrap_or(&url));
+        if !path.is_file() {
+            return Err(InstallError::Io(path, std::io::ErrorKind::NotFound.into()));
+        }
+        return Ok(path);
+    }
+
+    let target = dir.join(archive_name(&url));
+    let mut response = ureq::get(&url)
+        .call()
+        .map_err(|err| InstallError::Download(url.clone(), err))?;
+    let total = response.body().content_length().unwrap_or_default();
+    let mut reader = response.body_mut().as_reader();
+    let mut file =
+        std::fs::File::create(&target).map_err(|err| InstallError::Io(target.clone(), err))?;
+
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+    let mut done = 0;
+    loop {
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|err| InstallError::Io(target.clone(), err))?;
+        if read == 0 {
+            break;
+        }
+        file.write_all(&buffer[..read])
+            .map_err(|err| InstallError::Io(target.clone(), err))?;
+        done += read as u64;
+        progress(InstallProgress::new(InstallStage::Downloading, done, total));
+    }
+    Ok(target)
+}
+
+/// Compares the file with `expected`, supports plain MD5/SHA-256 hex and SRI strings.
+pub fn verify_checksum(
+    path: &Path,
+    expected: &str,
+    progress: &mut impl FnMut(InstallProgress),
+) -> Result<(), InstallError> {
+    let expected = expected.trim();
+    let (algorithm, value, base64) = match expected.split_once('-') {
+        Some((algorithm, value)) if algorithm.starts_with("sha") => (algorithm, value, true),
+        _ => match expected.len() {
+            32 => ("md5", expected, false),
+            64 => ("sha256", expected, false),
+            _ => return Err(InstallError::UnsupportedChecksum(expected.to_string())),
+        },
+    };
+    let mut hasher: Box<dyn sha2::digest::DynDigest> = match algorithm {
+        "md5" => Box::new(md5::Md5::new()),
+        "sha256" => Box::new(sha2::Sha256::new()),
+        "sha384" => Box::new(sha2::Sha384::new()),
+        "sha512" => Box::new(sha2::Sha512::new()),
+        _ => return Err(InstallError::UnsupportedChecksum(expected.to_string())),
+    };
+
+    let io_error = |err| InstallError::Io(path.to_path_buf(), err);
+    let mut file = std::fs::File::open(path).map_err(io_error)?;
+    let total = file.metadata().map(|meta| meta.len()).unwrap_or_default();
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+    let mut done = 0;
+    loop {
+        let read = file.read(&mut buffer).map_err(io_error)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        done += read as u64;
+        progress(InstallProgress::new(InstallStage::Verifying, done, total));
+    }
+
+    let digest = hasher.finalize();
+    let actual = if base64 {
+        base64::engine::general_purpose::STANDARD.encode(&digest)
+    } else {
+        digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+    };
+    // Base64 is case sensitive, hex digests are often written in upper case.
+    let matches = if base64 {
+        actual == value
+    } else {
+        actual.eq_ignore_ascii_case(value)
+    };
+    if matches {
+        Ok(())
+    } else {
+        Err(InstallError::ChecksumMismatch {
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+}
+
+/// Extracts `archive` into `target`, which ends up with the content of the archive root.
+pub fn extract(
+    archive: &Path,
+    target: &Path,
+    progress: &mut impl FnMut(InstallProgress),
+) -> Result<(), InstallError> {
+    let kind = ArchiveKind::from_path(archive)
+        .filter(ArchiveKind::supported_on_host)
+        .ok_or_else(|| InstallError::UnsupportedArchive(archive.to_path_buf()))?;
+    std::fs::create_dir_all(target).map_err(|err| InstallError::Io(target.to_path_buf(), err))?;
+    progress(InstallProgress::new(InstallStage::Extracting, 0, 0));
+    match kind {
+        ArchiveKind::TarXz => extract_tar_xz(archive, target, progress),
+        ArchiveKind::Zip => extract_zip(archive, target, progress),
+        ArchiveKind::Pkg => extract_pkg(archive, target),
+        ArchiveKind::Dmg => extract_dmg(archive, target),
+        ArchiveKind::Exe => run_installer(archive, target),
+    }
+}
+
+fn extract_tar_xz(
+    archive: &Path,
+    target: &Path,
+    progress: &mut impl FnMut(InstallProgress),
+) -> Result<(), InstallError> {
+    let io_error = |err| InstallError::Io(archive.to_path_buf(), err);
+    let file = std::fs::File::open(archive).map_err(io_error)?;
+    let total = file.metadata().map(|meta| meta.len()).unwrap_or_default();
+    let read = Arc::new(AtomicU64::new(0));
+    let reader = CountingReader {
+        inner: file,
+        read: read.clone(),
+    };
+
+    let mut tar = tar::Archive::new(xz2::read::XzDecoder::new(reader));
+    tar.set_preserve_permissions(true);
+    for entry in tar.entries().map_err(io_error)? {
+        let mut entry = entry.map_err(io_error)?;
+        // `unpack_in` refuses entries escaping the target directory.
+        entry.unpack_in(target).map_err(io_error)?;
+        progress(InstallProgress::new(
+            InstallStage::Extracting,
+            read.load(Ordering::Relaxed),
+            total,
+        ));
+    }
+    Ok(())
+}
+
+fn extract_zip(
+    archive: &Path,
+    target: &Path,
+    progress: &mut impl FnMut(InstallProgress),
+) -> Result<(), InstallError> {
+    let io_error = |err| InstallError::Io(archive.to_path_buf(), err);
+    let file = std::fs::File::open(archive).map_err(io_error)?;
+    let mut zip = zip::ZipArchive::new(file)
+        .map_err(|err| io_error(std::io::Error::new(std::io::ErrorKind::InvalidData, err)))?;
+    let total = zip.len() as u64;
+    for i in 0..zip.len() {
+        let mut entry = zip
+            .by_index(i)
+            .map_err(|err| io_error(std::io::Error::new(std::io::ErrorKind::InvalidData, err)))?;
+        // Entries escaping the target directory are skipped.
+        let Some(relative) = entry.enclosed_name() else {
+            continue;
+        };
+        let path = target.join(relative);
+        let path_error = |err| InstallError::Io(path.clone(), err);
+        if entry.is_dir() {
+            std::fs::create_dir_all(&path).map_err(path_error)?;
+        } else {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(path_error)?;
+            }
+            let mut file = std::fs::File::create(&path).map_err(path_error)?;
+            std::io::copy(&mut entry, &mut file).map_err(path_error)?;
+            #[cfg(unix)]
+            if let Some(mode) = entry.unix_mode() {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode))
+                    .map_err(path_error)?;
+            }
+        }
+        progress(InstallProgress::new(
+            InstallStage::Extracting,
+            i as u64 + 1,
+            total,
+        ));
+    }
+    Ok(())
+}
+
+/// Expands the package with `pkgutil` and moves payloads into `target`.
+fn extract_pkg(archive: &Path, target: &Path) -> Result<(), InstallError> {
+    let expanded = target.with_extension("expanded");
+    run(Command::new("pkgutil")
+        .arg("--expand-full")
+        .arg(archive)
+        .arg(&expanded))?;
+
+    let payloads: Vec<PathBuf> = WalkDir::new(&expanded)
+        .max_depth(2)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_dir() && entry.file_name() == "Payload")
+        .map(|entry| entry.into_path())
+        .collect();
+    for payload in payloads {
+        move_children(&payload, target)?;
+    }
+    let _ = std::fs::remove_dir_all(&expanded);
+    Ok(())
+}
+
+/// Mounts the disk image and installs the package or copies the app bundle it contains.
+fn extract_dmg(archive: &Path, target: &Path) -> Result<(), InstallError> {
+    let mount = target.with_extension("mount");
+    run(Command::new("hdiutil")
+        .args(["attach", "-nobrowse", "-readonly", "-mountpoint"])
+        .arg(&mount)
+        .arg(archive))?;
+
+    let result = std::fs::read_dir(&mount)
+        .map_err(|err| InstallError::Io(mount.clone(), err))
+        .and_then(|dir| {
+            let entries: Vec<PathBuf> = dir.flatten().map(|entry| entry.path()).collect();
+            if let Some(pkg) = entries
+                .iter()
+                .find(|path| path.extension() == Some("pkg".as_ref()))
+            {
+                return extract_pkg(pkg, target);
+            }
+            for app in entries
+                .iter()
+                .filter(|path| path.extension() == Some("app".as_ref()))
+            {
+                run(Command::new("cp").arg("-R").arg(app).arg(target))?;
+            }
+            Ok(())
+        });
+
+    let _ = Command::new("hdiutil").arg("detach").arg(&mount).status();
+    result
+}
+
+/// Runs the NSIS installer silently, `/D` has to be the last argument and can't be quoted.
+fn run_installer(archive: &Path, target: &Path) -> Result<(), InstallError> {
+    run(Command::new(archive)
+        .arg("/S")
+        .arg(format!("/D={}", target.display())))
+}
+
+fn run(command: &mut Command) -> Result<(), InstallError> {
+    let program = command.get_program().to_string_lossy().to_string();
+    let status = command
+        .status()
+        .map_err(|err| InstallError::Command(format!("Failed to run {}: {}", program, err)))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(InstallError::Command(format!(
+            "{} failed with {}",
+            program, status
+        )))
+    }
+}
+
+/// Moves entries of `from` into `to`, merging directories that already exist.
+pub(crate) fn move_children(from: &Path, to: &Path) -> Result<(), InstallError> {
+    std::fs::create_dir_all(to).map_err(|err| InstallError::Io(to.to_path_buf(), err))?;
+    let entries =
+        std::fs::read_dir(from).map_err(|err| InstallError::Io(from.to_path_buf(), err))?;
+    for entry in entries.flatten() {
+        let destination = to.join(entry.file_name());
+        if destination.is_dir() && entry.path().is_dir() {
+            move_children(&entry.path(), &destination)?;
+        } else {
+            std::fs::rename(entry.path(), &destination)
+                .map_err(|err| InstallError::Io(destination, err))?;
+        }
+    }
+    Ok(())
+}
+
+/// Downloads or picks the archive, verifies it and installs the editor as
+/// `<destination>/<version>`, the layout found by search paths. Returns the editor directory.
+pub fn install_editor(
+    request: &InstallRequest,
+    mut progress: impl FnMut(InstallProgress),
+) -> Result<PathBuf, InstallError> {
+    let staging = staging_dir(request);
+    std::fs::create_dir_all(&staging).map_err(|err| InstallError::Io(staging.clone(), err))?;
+
+    let result = install_from_staging(request, &staging, &mut progress);
+    let _ = std::fs::remove_dir_all(&staging);
+    result
+}
+
+/// Directory the archive is downloaded and extracted to, every version installed into the same
+/// search path gets its own.
+fn staging_dir(request: &InstallRequest) -> PathBuf {
+    let archive = match &request.source {
+        ArchiveSource::File(path) => path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        ArchiveSource::Url(url) => archive_name(url).to_string(),
+    };
+    let name = request
+        .version
+        .clone()
+        .or_else(|| editor_version::find_version(archive.as_bytes()))
+        .unwrap_or(archive);
+    request.destination.join(format!(".installing-{}", name))
+}
+
+fn install_from_staging(
+    request: &InstallRequest,
+    staging: &Path,
+    progress: &mut impl FnMut(InstallProgress),
+) -> Result<PathBuf, InstallError> {
+    let archive = match &request.source {
+        ArchiveSource::File(path) => path.clone(),
+        ArchiveSource::Url(url) => {
+            fetch_archive(url, request.mirror.as_deref(), staging, progress)?
+        }
+    };
+    if let Some(checksum) = request.checksum.as_deref().filter(|c| !c.trim().is_empty()) {
+        verify_checksum(&archive, checksum, progress)?;
+    }
+
+    let content = staging.join("content");
+    // Windows installers put the editor into `Editor`, like Unity Hub and the Linux archives do.
+    let extract_target = match ArchiveKind::from_path(&archive) {
+        Some(ArchiveKind::Exe) => content.join("Editor"),
+        _ => content.clone(),
+    };
+    extract(&archive, &extract_target, progress)?;
+    progress(InstallProgress::new(InstallStage::Finishing, 0, 0));
+
+    let base_in_content = [content.clone(), content.join("Editor")]
+        .into_iter()
+        .find(|dir| Configuration::is_unity_dir(dir))
+        .ok_or_else(|| InstallError::NotAnEditor(content.clone()))?;
+    let layout = EditorLayout::detect(&base_in_content)
+        .ok_or_else(|| InstallError::NotAnEditor(content.clone()))?;
+    let version = editor_version::detect(&base_in_content, layout)
+        .or_else(|| {
+            archive
+                .file_name()
+                .and_then(|name| editor_version::find_version(name.as_encoded_bytes()))
+        })
+        .ok_or_else(|| InstallError::NotAnEditor(content.clone()))?;
+
+    let root = request.destination.join(&version);
+    if root.exists() {
+        return Err(InstallError::AlreadyInstalled(root));
+    }
+    std::fs::rename(&content, &root).map_err(|err| InstallError::Io(root.clone(), err))?;
+
+    let relative = base_in_content
+        .strip_prefix(&content)
+        .unwrap_or(Path::new(""));
+    Ok(root.join(relative))
+}
+
+/// Counts bytes read from the compressed archive to report extraction progress.
+struct CountingReader<R> {
+    inner: R,
+    read: Arc<AtomicU64>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.read.fetch_add(read as u64, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests run in parallel, every call gets its own file.
+    fn verify(expected: &str) -> Result<(), InstallError> {
+        static NEXT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "rusty_hub_checksum_{}_{}",
+            std::process::id(),
+            NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        ));
+        std::fs::write(&path, b"abc").unwrap();
+        let result = verify_checksum(&path, expected, &mut |_| {});
+        let _ = std::fs::remove_file(&path);
+        result
+    }
+
+    #[test]
+    fn archive_name_ignores_query_and_fragment() {
+        assert_eq!(
+            archive_name("https://example.com/a/Unity-6000.0.23f1.tar.xz?token=abc&exp=1"),
+            "Unity-6000.0.23f1.tar.xz"
+        );
+        assert_eq!(
+            archive_name("https://example.com/a/UnitySetup64.exe#sha256"),
+            "UnitySetup64.exe"
+        );
+        assert_eq!(
+            archive_name("https://example.com/a/Unity.tar.xz"),
+            "Unity.tar.xz"
+        );
+        assert_eq!(
+            archive_name("https://example.com/?file=Unity.zip"),
+            "download"
+        );
+    }
+
+    #[test]
+    fn staging_dir_is_named_after_the_version() {
+        let request = |source, version: Option<&str>| InstallRequest {
+            source,
+            checksum: None,
+            destination: PathBuf::from("/opt/unity"),
+            mirror: None,
+            version: version.map(str::to_string),
+        };
+        let url = ArchiveSource::Url("https://example.com/a/Unity.tar.xz?t=1".to_string());
+        assert_eq!(
+            staging_dir(&request(url.clone(), Some("2022.3.10f1"))),
+            Path::new("/opt/unity/.installing-2022.3.10f1")
+        );
+        assert_eq!(
+            staging_dir(&request(url, None)),
+            Path::new("/opt/unity/.installing-Unity.tar.xz")
+        );
+        let file = ArchiveSource::File(PathBuf::from("/tmp/Unity-6000.0.23f1.tar.xz"));
+        assert_eq!(
+            staging_dir(&request(file, None)),
+            Path::new("/opt/unity/.installing-6000.0.23f1")
+        );
+    }
+
+    #[test]
+    fn accepts_hex_digests_in_any_case() {
+        assert!(verify("900150983cd24fb0d6963f7d28e17f72").is_ok());
+        assert!(
+            verify("BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD ").is_ok()
+        );
+    }
+
+    #[test]
+    fn accepts_sri_strings() {
+        assert!(verify(
+            "sha512-3a81oZNherrMQXNJriBBMRLm+k6JqX6iCp7u5ktV05ohkpkqJ0/BqDa6PCOj/uu9RU1EI2Q86A4qmslPpUyknw=="
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn reports_mismatches_and_unknown_formats() {
+        assert!(matches!(
+            verify("00000000000000000000000000000000"),
+            Err(InstallError::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(
+            verify("sha1-qZk+NkcGgWq6PiVxeFDCbJzQ2J0="),
+            Err(InstallError::UnsupportedChecksum(_))
+        ));
+        assert!(matches!(
+            verify("1234"),
+            Err(InstallError::UnsupportedChecksum(_))
+        ));
+    }
+}
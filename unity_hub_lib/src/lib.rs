@@ -1,6 +1,30 @@
+pub mod architecture;
+pub mod batch;
+pub mod build_profile;
 pub mod config;
 pub mod consts;
+pub mod discovery;
+pub mod editor_layout;
+pub mod editor_log;
+pub mod editor_module;
+pub mod editor_process;
+pub mod editor_version;
+pub mod fingerprint;
 pub mod hub;
+pub mod installer;
+pub mod license;
+pub mod log_tail;
+pub mod module_installer;
+pub mod paths;
+pub mod process;
+pub mod project_root;
+pub mod project_scan;
 pub mod project_template;
+pub mod release_catalog;
+pub mod search_path;
+pub mod test_runner;
+pub mod uninstall;
 pub mod unity_editor;
 pub mod unity_project;
+pub mod unity_version;
+pub mod watcher;
@@ -0,0 +1,351 @@
+use crate::consts::{LICENSE_EXPIRY_WARNING_DAYS, UNITY_LICENSE_PATH};
+use crate::paths;
+use serde::Serialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseKind {
+    Personal,
+    Plus,
+    Pro,
+    Unknown,
+}
+
+impl LicenseKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LicenseKind::Personal => "Personal",
+            LicenseKind::Plus => "Plus",
+            LicenseKind::Pro => "Pro",
+            LicenseKind::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Hardware value the license was activated for.
+#[derive(Debug, Serialize, Clone)]
+pub struct MachineBinding {
+    pub key: String,
+    pub value: String,
+}
+
+/// Content of `Unity_lic.ulf`. The signature isn't verified, the file is only read.
+#[derive(Debug, Serialize, Clone)]
+pub struct UnityLicense {
+    pub kind: LicenseKind,
+    /// Serial with most characters replaced, e.g. `F4-XXXX-XXXX-XXXX-XXXX-1234`.
+    pub serial_masked: String,
+    pub activated: Option<String>,
+    pub start_date: Option<String>,
+    /// Last time the license was refreshed with the license server.
+    pub updated: Option<String>,
+    /// End of the license, `None` when it doesn't expire.
+    pub expires: Option<String>,
+    pub machine_id: Option<String>,
+    pub bindings: Vec<MachineBinding>,
+    /// Tags of the entitlements, e.g. `UnityPersonal`.
+    pub entitlements: Vec<String>,
+    pub signed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseStatus {
+    Valid,
+    /// Days left, below `LICENSE_EXPIRY_WARNING_DAYS`.
+    ExpiresSoon(u64),
+    Expired,
+    /// The end date couldn't be read, the license may have expired.
+    Unknown,
+}
+
+#[derive(Debug)]
+pub enum LicenseError {
+    Io(std::io::Error),
+    Xml(roxmltree::Error),
+    /// The document has no `License` element.
+    NotLicense,
+}
+
+impl fmt::Display for LicenseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LicenseError::Io(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                write!(f, "No license file, the editor was never activated")
+            }
+            LicenseError::Io(err) => write!(f, "Failed to read the license file: {}", err),
+            LicenseError::Xml(err) => write!(f, "Invalid license file: {}", err),
+            LicenseError::NotLicense => write!(f, "Not a Unity license file"),
+        }
+    }
+}
+
+impl std::error::Error for LicenseError {}
+
+impl UnityLicense {
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires.as_deref().and_then(parse_timestamp)
+    }
+
+    pub fn status(&self, now: SystemTime) -> LicenseStatus {
+        if self.expires.is_none() {
+            return LicenseStatus::Valid;
+        }
+        let Some(expires) = self.expires_at() else {
+            return LicenseStatus::Unknown;
+        };
+        match expires.duration_since(now) {
+            Err(_) => LicenseStatus::Expired,
+            Ok(left)
+                if left < Duration::from_secs(LICENSE_EXPIRY_WARNING_DAYS * SECONDS_PER_DAY) =>
+            {
+                LicenseStatus::ExpiresSoon(left.as_secs() / SECONDS_PER_DAY)
+            }
+            Ok(_) => LicenseStatus::Valid,
+        }
+    }
+}
+
+/// Default license file of the machine, it may not exist.
+pub fn license_path() -> PathBuf {
+    paths::expand(UNITY_LICENSE_PATH)
+}
+
+pub fn read_license(path: &Path) -> Result<UnityLicense, LicenseError> {
+    let content = std::fs::read_to_string(path).map_err(LicenseError::Io)?;
+    parse_license(&content)
+}
+
+pub fn parse_license(xml: &str) -> Result<UnityLicense, LicenseError> {
+    let document = roxmltree::Document::parse(xml).map_err(LicenseError::Xml)?;
+    let license = document
+        .descendants()
+        .find(|node| node.has_tag_name("License"))
+        .ok_or(LicenseError::NotLicense)?;
+    let value = |name: &str| {
+        license
+            .children()
+            .find(|child| child.has_tag_name(name))
+            .and_then(|child| child.attribute("Value"))
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    // Tag, type and end date.
+    let entitlements: Vec<(String, Option<&str>, Option<String>)> = license
+        .descendants()
+        .filter(|node| node.has_tag_name("Entitlement"))
+        .filter_map(|node| {
+            let tag = node.attribute("Tag")?.to_string();
+            let valid_to = node.attribute("ValidTo").map(str::to_string);
+            Some((tag, node.attribute("Type"), valid_to))
+        })
+        .collect();
+    let kind = kind_of(entitlements.iter().map(|(tag, _, _)| tag.as_str()));
+    // Subscriptions without a stop date end with their tier entitlement, feature entitlements
+    // like `DarkSkin` end on their own.
+    let expires = value("StopDate").or_else(|| {
+        entitlements
+            .iter()
+            .filter(|(tag, kind_type, _)| *kind_type == Some("EDITOR") || is_tier(tag, kind))
+            .filter_map(|(_, _, valid_to)| valid_to.clone())
+            .filter(|valid_to| parse_timestamp(valid_to).is_some())
+            .filter(|valid_to| !valid_to.starts_with("9999"))
+            .min()
+    });
+
+    Ok(UnityLicense {
+        kind,
+        serial_masked: value("SerialMasked").unwrap_or_default(),
+        activated: value("InitialActivationDate"),
+        start_date: value("StartDate"),
+        updated: value("UpdateDate"),
+        expires,
+        machine_id: value("MachineID"),
+        bindings: license
+            .descendants()
+            .filter(|node| node.has_tag_name("Binding"))
+            .map(|node| MachineBinding {
+                key: node.attribute("Key").unwrap_or_default().to_string(),
+                value: node.attribute("Value").unwrap_or_default().to_string(),
+            })
+            .collect(),
+        entitlements: entitlements.into_iter().map(|(tag, _, _)| tag).collect(),
+        signed: document
+            .descendants()
+            .any(|node| node.has_tag_name("Signature")),
+    })
+}
+
+/// Entitlement tags of the license tiers, highest first.
+const TIERS: [(&str, LicenseKind); 3] = [
+    ("UnityPro", LicenseKind::Pro),
+    ("UnityPlus", LicenseKind::Plus),
+    ("UnityPersonal", LicenseKind::Personal),
+];
+
+/// The highest tier found in the entitlement tags. Other entitlements, e.g. `UnityProfiler`,
+/// don't tell the tier.
+fn kind_of<'a>(tags: impl Iterator<Item = &'a str>) -> LicenseKind {
+    let tags: Vec<&str> = tags.collect();
+    TIERS
+        .iter()
+        .find(|(tier, _)| tags.contains(tier))
+        .map_or(LicenseKind::Unknown, |(_, kind)| *kind)
+}
+
+fn is_tier(tag: &str, kind: LicenseKind) -> bool {
+    TIERS.contains(&(tag, kind))
+}
+
+/// Parses `YYYY-MM-DDTHH:MM:SS` as written in license files, in UTC.
+fn parse_timestamp(text: &str) -> Option<SystemTime> {
+    let (date, time) = text.split_once('T').unwrap_or((text, "00:00:00"));
+    let mut date = date.split('-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    let mut time = time
+        .trim_end_matches('Z')
+        .split(':')
+        .map(|part| part.split('.').next()?.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (
+        time.next().flatten().unwrap_or(0),
+        time.next().flatten().unwrap_or(0),
+        time.next().flatten().unwrap_or(0),
+    );
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let days = u64::try_from(days_from_civil(year, month, day)).ok()?;
+    let seconds = days * SECONDS_PER_DAY + hours * 3600 + minutes * 60 + seconds;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/license/Unity_lic.ulf"
+    );
+
+    fn license() -> UnityLicense {
+        read_license(Path::new(FIXTURE)).unwrap()
+    }
+
+    fn date(text: &str) -> SystemTime {
+        parse_timestamp(text).unwrap()
+    }
+
+    #[test]
+    fn reads_license_fields() {
+        let license = license();
+        assert_eq!(license.kind, LicenseKind::Personal);
+        assert_eq!(license.serial_masked, "F4-BQD6-G4AP-PXK8-4948-XXXX");
+        assert_eq!(license.activated.as_deref(), Some("2023-01-12T10:15:03"));
+        assert_eq!(license.updated.as_deref(), Some("2023-09-28T08:41:17"));
+        assert_eq!(license.bindings.len(), 2);
+        assert_eq!(license.bindings[1].value, "C02XK1ZJJG5J");
+        assert_eq!(license.entitlements.len(), 4);
+        assert!(license.signed);
+    }
+
+    #[test]
+    fn expires_with_the_tier_entitlement() {
+        // `DarkSkin` ends earlier, but only the `UnityPersonal` date ends the license.
+        assert_eq!(license().expires.as_deref(), Some("2024-01-12T00:00:00"));
+    }
+
+    #[test]
+    fn tier_entitlements_ending_in_9999_never_expire() {
+        let license = parse_license(
+            r#"<root><License><Entitlements>
+                <Entitlement Tag="UnityPro" Type="EDITOR" ValidTo="9999-12-31T00:00:00"/>
+                <Entitlement Tag="DarkSkin" Type="EDITOR_FEATURE" ValidTo="2023-12-31T00:00:00"/>
+            </Entitlements></License></root>"#,
+        )
+        .unwrap();
+        assert_eq!(license.kind, LicenseKind::Pro);
+        assert_eq!(license.expires, None);
+        assert!(!license.signed);
+    }
+
+    #[test]
+    fn reads_tier_from_exact_tags() {
+        let kind = |tags: &[&str]| kind_of(tags.iter().copied());
+        assert_eq!(kind(&["UnityProfiler", "ProBuilder"]), LicenseKind::Unknown);
+        assert_eq!(
+            kind(&["ProBuilder", "UnityPersonal"]),
+            LicenseKind::Personal
+        );
+        assert_eq!(kind(&["UnityPersonal", "UnityPlus"]), LicenseKind::Plus);
+        assert_eq!(
+            kind(&["UnityPlus", "UnityPro", "UnityPersonal"]),
+            LicenseKind::Pro
+        );
+    }
+
+    #[test]
+    fn status_follows_the_end_date() {
+        let mut license = license();
+        assert_eq!(
+            license.status(date("2023-06-01T00:00:00")),
+            LicenseStatus::Valid
+        );
+        assert_eq!(
+            license.status(date("2024-01-02T00:00:00")),
+            LicenseStatus::ExpiresSoon(10)
+        );
+        assert_eq!(
+            license.status(date("2024-01-13T00:00:00")),
+            LicenseStatus::Expired
+        );
+        license.expires = Some("someday".to_string());
+        assert_eq!(license.status(SystemTime::now()), LicenseStatus::Unknown);
+        license.expires = None;
+        assert_eq!(license.status(SystemTime::now()), LicenseStatus::Valid);
+    }
+
+    #[test]
+    fn parses_license_timestamps() {
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00"), Some(UNIX_EPOCH));
+        assert_eq!(
+            parse_timestamp("2023-12-31T10:20:30"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_704_018_030))
+        );
+        assert_eq!(
+            parse_timestamp("2023-12-31T10:20:30.123Z"),
+            parse_timestamp("2023-12-31T10:20:30")
+        );
+        assert_eq!(
+            parse_timestamp("2023-12-31"),
+            parse_timestamp("2023-12-31T00:00:00")
+        );
+        assert_eq!(parse_timestamp("2023-13-01T00:00:00"), None);
+        assert_eq!(parse_timestamp("1969-12-31T00:00:00"), None);
+        assert_eq!(parse_timestamp("never"), None);
+    }
+
+    #[test]
+    fn counts_days_from_the_epoch() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(9999, 12, 31), 2_932_896);
+    }
+}
@@ -0,0 +1,75 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Reads lines appended to a log file since the last call, starting over when the file was
+/// truncated or replaced by a shorter one.
+#[derive(Debug, Clone)]
+pub struct LogTail {
+    path: PathBuf,
+    offset: u64,
+    /// Last line without its line break yet.
+    partial: String,
+}
+
+impl LogTail {
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            offset: 0,
+            partial: String::new(),
+        }
+    }
+
+    /// Follows only what is written from now on.
+    pub fn from_end(path: &Path) -> Self {
+        let mut tail = Self::new(path);
+        tail.offset = std::fs::metadata(path).map_or(0, |meta| meta.len());
+        tail
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Complete lines written since the last call, the file missing counts as empty.
+    pub fn read_lines(&mut self) -> Vec<String> {
+        let Ok(mut file) = File::open(&self.path) else {
+            return Vec::new();
+        };
+        let len = file.metadata().map_or(0, |meta| meta.len());
+        if len < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset || file.seek(SeekFrom::Start(self.offset)).is_err() {
+            return Vec::new();
+        }
+        let mut bytes = Vec::new();
+        let Ok(read) = file.read_to_end(&mut bytes) else {
+            return Vec::new();
+        };
+        self.offset += read as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+
+        let Some(end) = self.partial.rfind('\n') else {
+            return Vec::new();
+        };
+        let rest = self.partial.split_off(end + 1);
+        let complete = std::mem::replace(&mut self.partial, rest);
+        complete
+            .lines()
+            .map(|line| line.trim_end_matches('\r').to_string())
+            .collect()
+    }
+
+    /// Remaining lines including the last one without a line break, call once the writer is done.
+    pub fn read_remaining(&mut self) -> Vec<String> {
+        let mut lines = self.read_lines();
+        let rest = std::mem::take(&mut self.partial);
+        if !rest.is_empty() {
+            lines.push(rest.trim_end_matches('\r').to_string());
+        }
+        lines
+    }
+}
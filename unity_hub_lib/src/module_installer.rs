@@ -0,0 +1,237 @@
+use crate::editor_layout::EditorLayout;
+use crate::editor_module::EditorModule;
+use crate::installer::{self, ArchiveKind, InstallError, InstallProgress};
+use crate::unity_editor::UnityEditor;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Folder `{UNITY_PATH}` in module destinations stands for. Windows and Linux destinations
+/// include the `Editor` part of hub installs in `<version>/Editor`, on macOS and for editors
+/// in a folder with another name it's the editor folder.
+pub fn unity_path(editor: &UnityEditor) -> PathBuf {
+    unity_path_of(Path::new(&editor.base_path), editor.layout)
+}
+
+fn unity_path_of(base_path: &Path, layout: EditorLayout) -> PathBuf {
+    match base_path.parent() {
+        Some(parent) if in_version_dir(base_path, layout) => parent.to_path_buf(),
+        _ => base_path.to_path_buf(),
+    }
+}
+
+fn in_version_dir(base_path: &Path, layout: EditorLayout) -> bool {
+    layout != EditorLayout::MacOs && base_path.file_name().is_some_and(|name| name == "Editor")
+}
+
+fn resolve(editor: &UnityEditor, path: &str) -> PathBuf {
+    resolve_in(Path::new(&editor.base_path), editor.layout, path)
+}
+
+fn resolve_in(base_path: &Path, layout: EditorLayout, path: &str) -> PathBuf {
+    let mut parts = path
+        .trim_start_matches("{UNITY_PATH}")
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty())
+        .peekable();
+    // `{UNITY_PATH}/Editor` is the editor folder itself when it isn't named `Editor`.
+    if layout != EditorLayout::MacOs
+        && !in_version_dir(base_path, layout)
+        && parts.peek() == Some(&"Editor")
+    {
+        parts.next();
+    }
+    parts.fold(unity_path_of(base_path, layout), |path, part| {
+        path.join(part)
+    })
+}
+
+/// The module with `id` followed by its sub modules and a missing parent, skipping installed ones.
+pub fn modules_to_install(modules: &[EditorModule], id: &str) -> Vec<EditorModule> {
+    let Some(module) = modules.iter().find(|module| module.id == id) else {
+        return Vec::new();
+    };
+    let parent = module
+        .parent
+        .as_deref()
+        .and_then(|parent| modules.iter().find(|module| module.id == parent));
+
+    parent
+        .into_iter()
+        .chain(std::iter::once(module))
+        .chain(module.sub_modules(modules))
+        .filter(|module| !module.installed())
+        .cloned()
+        .collect()
+}
+
+/// Downloads and installs `modules` into the editor in the given order, marking each of them as
+/// installed in `modules.json` right after it's in place. `modules` come from the editor's
+/// `modules.json` or the release catalog, see `ReleaseModule::to_editor_modules`.
+pub fn install_modules(
+    editor: &UnityEditor,
+    modules: &[EditorModule],
+    mirror: Option<&str>,
+    mut progress: impl FnMut(&EditorModule, InstallProgress),
+) -> Result<(), InstallError> {
+    let staging = unity_path(editor).join(".installing");
+    std::fs::create_dir_all(&staging).map_err(|err| InstallError::Io(staging.clone(), err))?;
+
+    let mut result = Ok(());
+    for module in modules {
+        result = install_module(editor, module, mirror, &staging, &mut |p| {
+            progress(module, p)
+        })
+        .and_then(|_| mark_installed(editor, modules, &module.id));
+        if result.is_err() {
+            break;
+        }
+    }
+    let _ = std::fs::remove_dir_all(&staging);
+    result
+}
+
+fn install_module(
+    editor: &UnityEditor,
+    module: &EditorModule,
+    mirror: Option<&str>,
+    staging: &Path,
+    progress: &mut impl FnMut(InstallProgress),
+) -> Result<(), InstallError> {
+    let url = module
+        .download_url
+        .as_deref()
+        .ok_or_else(|| InstallError::NotInstallable(module.name.clone()))?;
+    let archive = installer::fetch_archive(url, mirror, staging, progress)?;
+    if let Some(checksum) = module.checksum.as_deref().filter(|c| !c.trim().is_empty()) {
+        installer::verify_checksum(&archive, checksum, progress)?;
+    }
+
+    // Installers without destination, like documentation on Windows, pick the location themselves.
+    let destination = match (&module.destination, ArchiveKind::from_path(&archive)) {
+        (Some(destination), _) => resolve(editor, destination),
+        (None, Some(ArchiveKind::Exe)) => unity_path(editor),
+        (None, _) => return Err(InstallError::NotInstallable(module.name.clone())),
+    };
+    installer::extract(&archive, &destination, progress)?;
+
+    if let Some(rename) = &module.extracted_path_rename {
+        let from = resolve(editor, &rename.from);
+        let to = resolve(editor, &rename.to);
+        if from.exists() && from != to {
+            if let Some(parent) = to.parent() {
+                std::fs::create_dir_all(parent).map_err(|err| InstallError::Io(to.clone(), err))?;
+            }
+            installer::move_children(&from, &to)?;
+            let _ = std::fs::remove_dir_all(&from);
+        }
+    }
+    if archive.starts_with(staging) {
+        let _ = std::fs::remove_file(&archive);
+    }
+    Ok(())
+}
+
+/// Sets `selected` and `isInstalled` in `modules.json`, keeping fields the hub doesn't know about.
+/// Editors without the file get one written from `modules`.
+fn mark_installed(
+    editor: &UnityEditor,
+    modules: &[EditorModule],
+    id: &str,
+) -> Result<(), InstallError> {
+    // Only the file of this editor, a `modules.json` in a shared parent folder belongs to
+    // another one.
+    let unity_path = unity_path(editor);
+    let path = [Path::new(&editor.base_path), &unity_path]
+        .into_iter()
+        .map(|dir| dir.join("modules.json"))
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| unity_path.join("modules.json"));
+    let io_error = |err| InstallError::Io(path.clone(), err);
+
+    let mut entries: Vec<Value> = match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|err| io_error(std::io::Error::new(std::io::ErrorKind::InvalidData, err)))?,
+        Err(_) => modules
+            .iter()
+            .filter_map(|module| serde_json::to_value(module).ok())
+            .collect(),
+    };
+    for entry in entries
+        .iter_mut()
+        .filter(|entry| entry.get("id").and_then(Value::as_str) == Some(id))
+    {
+        if let Some(entry) = entry.as_object_mut() {
+            entry.insert("selected".to_string(), Value::Bool(true));
+            entry.insert("isInstalled".to_string(), Value::Bool(true));
+        }
+    }
+
+    let content = serde_json::to_string_pretty(&entries)
+        .map_err(|err| io_error(std::io::Error::new(std::io::ErrorKind::InvalidData, err)))?;
+    std::fs::write(&path, content).map_err(io_error)
+}
+
+/// Marks modules as installed when their destination exists, for lists coming from the release
+/// catalog instead of the editor's `modules.json`.
+pub fn detect_installed(editor: &UnityEditor, modules: &mut [EditorModule]) {
+    for module in modules {
+        if let Some(destination) = &module.destination {
+            module.is_installed = Some(resolve(editor, destination).exists());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unity_path_is_the_version_folder_of_hub_installs() {
+        let base_path = Path::new("Hub").join("2022.3.10f1").join("Editor");
+        assert_eq!(
+            unity_path_of(&base_path, EditorLayout::Windows),
+            Path::new("Hub").join("2022.3.10f1")
+        );
+        assert_eq!(
+            resolve_in(
+                &base_path,
+                EditorLayout::Linux,
+                "{UNITY_PATH}/Editor/Data/PlaybackEngines/WebGLSupport"
+            ),
+            base_path
+                .join("Data")
+                .join("PlaybackEngines")
+                .join("WebGLSupport")
+        );
+    }
+
+    #[test]
+    fn unity_path_is_the_editor_folder_elsewhere() {
+        let base_path = Path::new("Editors").join("unity-2022");
+        assert_eq!(unity_path_of(&base_path, EditorLayout::Linux), base_path);
+        assert_eq!(
+            resolve_in(
+                &base_path,
+                EditorLayout::Windows,
+                "{UNITY_PATH}\\Editor\\Data\\PlaybackEngines\\AndroidPlayer"
+            ),
+            base_path
+                .join("Data")
+                .join("PlaybackEngines")
+                .join("AndroidPlayer")
+        );
+        let base_path = Path::new("Applications")
+            .join("Unity")
+            .join("Hub")
+            .join("Editor");
+        assert_eq!(unity_path_of(&base_path, EditorLayout::MacOs), base_path);
+        assert_eq!(
+            resolve_in(
+                &base_path,
+                EditorLayout::MacOs,
+                "{UNITY_PATH}/PlaybackEngines/iOSSupport"
+            ),
+            base_path.join("PlaybackEngines").join("iOSSupport")
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+
+/// Expands `~`, `$VAR`, `${VAR}` and `%VAR%` in a user supplied path.
+/// Unknown variables are left untouched so the result is still recognizable.
+pub fn expand(path: &str) -> PathBuf {
+    let mut result = String::new();
+    let mut rest = path;
+
+    if let Some(stripped) = rest.strip_prefix('~') {
+        if stripped.is_empty() || stripped.starts_with(['/', '\\']) {
+            if let Some(home) = home_dir() {
+                result.push_str(&home);
+                rest = stripped;
+            }
+        }
+    }
+
+    while let Some(start) = rest.find(['$', '%']) {
+        result.push_str(&rest[..start]);
+        let tail = &rest[start + 1..];
+        let (name, consumed) = if rest[start..].starts_with('%') {
+            match tail.find('%') {
+                Some(end) => (&tail[..end], end + 1),
+                None => ("", 0),
+            }
+        } else if let Some(braced) = tail.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            }
+        } else {
+            let end = tail
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(tail.len());
+            (&tail[..end], end)
+        };
+
+        match lookup(name) {
+            Some(value) => {
+                result.push_str(&value);
+                rest = &tail[consumed..];
+            }
+            None => {
+                result.push_str(&rest[start..start + 1]);
+                rest = tail;
+            }
+        }
+    }
+    result.push_str(rest);
+
+    PathBuf::from(result)
+}
+
+/// Replaces the home directory prefix with `~` so stored paths stay portable.
+pub fn contract(path: &str) -> String {
+    if let Some(home) = home_dir() {
+        if let Some(rest) = path.strip_prefix(home.as_str()) {
+            if rest.is_empty() || rest.starts_with(['/', '\\']) {
+                return format!("~{}", rest);
+            }
+        }
+    }
+    path.to_string()
+}
+
+fn lookup(name: &str) -> Option<String> {
+    if name.is_empty() {
+        return None;
+    }
+    std::env::var(name).ok()
+}
+
+fn home_dir() -> Option<String> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()
+        .filter(|home| !home.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_home() {
+        let home = home_dir().expect("tests need HOME or USERPROFILE");
+        assert_eq!(expand("~"), PathBuf::from(&home));
+        assert_eq!(
+            expand("~/Unity/Hub"),
+            PathBuf::from(format!("{}/Unity/Hub", home))
+        );
+        // Only a leading `~` followed by a separator is the home directory.
+        assert_eq!(expand("~user/Unity"), PathBuf::from("~user/Unity"));
+        assert_eq!(expand("/opt/~/Unity"), PathBuf::from("/opt/~/Unity"));
+    }
+
+    #[test]
+    fn expands_variables() {
+        // Tests run in parallel, the variable isn't used by any other test.
+        std::env::set_var("RUSTY_HUB_TEST_EDITORS", "/opt/editors");
+        assert_eq!(
+            expand("$RUSTY_HUB_TEST_EDITORS/2022.3"),
+            PathBuf::from("/opt/editors/2022.3")
+        );
+        assert_eq!(
+            expand("${RUSTY_HUB_TEST_EDITORS}_old"),
+            PathBuf::from("/opt/editors_old")
+        );
+        assert_eq!(
+            expand("%RUSTY_HUB_TEST_EDITORS%\\Hub"),
+            PathBuf::from("/opt/editors\\Hub")
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_variables() {
+        assert_eq!(
+            expand("$RUSTY_HUB_TEST_MISSING/Unity"),
+            PathBuf::from("$RUSTY_HUB_TEST_MISSING/Unity")
+        );
+        assert_eq!(
+            expand("%RUSTY_HUB_TEST_MISSING%\\Unity"),
+            PathBuf::from("%RUSTY_HUB_TEST_MISSING%\\Unity")
+        );
+        assert_eq!(expand("100%/${unclosed"), PathBuf::from("100%/${unclosed"));
+    }
+
+    #[test]
+    fn contracts_home_and_round_trips() {
+        let home = home_dir().expect("tests need HOME or USERPROFILE");
+        let path = format!("{}/Unity/Hub/Editor", home);
+        assert_eq!(contract(&path), "~/Unity/Hub/Editor");
+        assert_eq!(expand(&contract(&path)), PathBuf::from(&path));
+        assert_eq!(contract(&home), "~");
+        // A sibling folder sharing the prefix isn't inside the home directory.
+        let sibling = format!("{}-backup/Unity", home);
+        assert_eq!(contract(&sibling), sibling);
+        assert_eq!(contract("/opt/unity"), "/opt/unity");
+        assert_eq!(expand(&contract("/opt/unity")), PathBuf::from("/opt/unity"));
+    }
+}
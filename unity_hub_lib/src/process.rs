@@ -0,0 +1,11 @@
+use std::process::Command;
+
+/// Starts `command` without waiting for it, a background thread reaps the process once it
+/// exits so it doesn't linger as a zombie.
+pub fn spawn_detached(command: &mut Command) -> std::io::Result<()> {
+    let mut child = command.spawn()?;
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
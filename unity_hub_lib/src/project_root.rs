@@ -0,0 +1,49 @@
+use crate::paths;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Folder containing projects, rescanned on startup and watched for new ones.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ProjectRoot {
+    /// Path as typed by the user, may contain `~` and environment variables.
+    pub path: String,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Outcome of the last scan of a single project root.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectRootReport {
+    pub projects: usize,
+    /// Known projects inside of the root that no longer exist.
+    pub missing: usize,
+    pub errors: Vec<String>,
+}
+
+impl ProjectRoot {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            label: String::new(),
+            enabled: true,
+        }
+    }
+
+    pub fn resolved(&self) -> PathBuf {
+        paths::expand(&self.path)
+    }
+
+    pub fn display_name(&self) -> &str {
+        if self.label.is_empty() {
+            &self.path
+        } else {
+            &self.label
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::unity_project::UnityProject;
+use glob::{MatchOptions, Pattern};
+use ignore::gitignore::Gitignore;
+use ignore::Match;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use walkdir::WalkDir;
+
+pub const DEFAULT_SCAN_DEPTH: usize = 6;
+
+/// Directories that never contain projects but can hold a lot of files.
+pub const HEAVY_DIRS: [&str; 9] = [
+    ".git",
+    ".hg",
+    ".svn",
+    "Library",
+    "Temp",
+    "Logs",
+    "obj",
+    "node_modules",
+    "__pycache__",
+];
+
+/// Shared flag stopping a scan running on another thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// How a folder is walked looking for projects.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct ScanOptions {
+    pub max_depth: usize,
+    /// Directory names that are never entered, e.g. `node_modules`.
+    pub skip_dirs: Vec<String>,
+    /// Glob patterns matched against directories relative to the scanned folder.
+    pub ignore: Vec<String>,
+    /// Skip directories ignored by `.gitignore` files found on the way.
+    pub respect_gitignore: bool,
+    /// Enter symlinked directories, projects reachable twice are reported once.
+    pub follow_symlinks: bool,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_SCAN_DEPTH,
+            skip_dirs: HEAVY_DIRS.iter().map(|dir| dir.to_string()).collect(),
+            ignore: Vec::new(),
+            respect_gitignore: true,
+            follow_symlinks: false,
+        }
+    }
+}
+
+impl ScanOptions {
+    /// Returns error messages for ignore patterns that are not valid globs.
+    pub fn pattern_errors(&self) -> Vec<String> {
+        self.ignore
+            .iter()
+            .filter_map(|pattern| {
+                Pattern::new(pattern)
+                    .err()
+                    .map(|e| format!("Invalid pattern \"{}\": {}", pattern, e.msg))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Name is listed in `ScanOptions::skip_dirs`.
+    SkipDir,
+    /// Matches one of `ScanOptions::ignore`.
+    Ignored,
+    GitIgnored,
+    /// Path is not valid UTF-8, projects are stored as strings.
+    InvalidPath,
+}
+
+#[derive(Debug, Clone)]
+pub enum ScanEvent {
+    /// Directory the scan is currently looking at.
+    Entered(PathBuf),
+    Found(UnityProject),
+    /// Project reached a second time, e.g. through a symlink.
+    Duplicate(PathBuf),
+    Skipped(PathBuf, SkipReason),
+}
+
+/// Outcome of a single scan.
+#[derive(Debug, Clone, Default)]
+pub struct ScanReport {
+    pub found: usize,
+    /// Projects that were found more than once or were already known.
+    pub duplicates: usize,
+    pub skipped: usize,
+    pub errors: Vec<String>,
+    pub cancelled: bool,
+}
+
+/// Walks `root` looking for projects, reporting every directory and project as soon as it's
+/// found. Project folders and directories rejected by `options` are not entered.
+pub fn scan_projects(
+    root: &Path,
+    options: &ScanOptions,
+    cancel: &CancelToken,
+    mut on_event: impl FnMut(ScanEvent),
+) -> ScanReport {
+    let mut report = ScanReport {
+        errors: options.pattern_errors(),
+        ..Default::default()
+    };
+    let patterns: Vec<Pattern> = options
+        .ignore
+        .iter()
+        .filter_map(|pattern| Pattern::new(pattern).ok())
+        .collect();
+    let match_options = MatchOptions {
+        case_sensitive: !cfg!(windows),
+        ..Default::default()
+    };
+    // `.gitignore` files of the directories the walk is currently inside of, innermost last.
+    let mut gitignores: Vec<Gitignore> = Vec::new();
+    let mut visited = HashSet::new();
+
+    let mut walker = WalkDir::new(root)
+        .max_depth(options.max_depth)
+        .follow_links(options.follow_symlinks)
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        if cancel.is_cancelled() {
+            report.cancelled = true;
+            return report;
+        }
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                report.errors.push(e.to_string());
+                continue;
+            }
+        };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        gitignores.retain(|gitignore| path.starts_with(gitignore.path()));
+
+        let skip = if entry.depth() == 0 {
+            None
+        } else if path.to_str().is_none() {
+            Some(SkipReason::InvalidPath)
+        } else if options
+            .skip_dirs
+            .iter()
+            .any(|dir| entry.file_name() == dir.as_str())
+        {
+            Some(SkipReason::SkipDir)
+        } else if is_ignored(root, path, &patterns, match_options) {
+            Some(SkipReason::Ignored)
+        } else if options.respect_gitignore && is_gitignored(&gitignores, path) {
+            Some(SkipReason::GitIgnored)
+        } else {
+            None
+        };
+        if let Some(reason) = skip {
+            walker.skip_current_dir();
+            report.skipped += 1;
+            on_event(ScanEvent::Skipped(path.to_path_buf(), reason));
+            continue;
+        }
+
+        on_event(ScanEvent::Entered(path.to_path_buf()));
+        if let Some(project) = UnityProject::try_get_project_at_path(&path.to_string_lossy()) {
+            // There is nothing more to find inside of a project.
+            walker.skip_current_dir();
+            let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            if visited.insert(canonical) {
+                report.found += 1;
+                on_event(ScanEvent::Found(project));
+            } else {
+                report.duplicates += 1;
+                on_event(ScanEvent::Duplicate(path.to_path_buf()));
+            }
+            continue;
+        }
+
+        let gitignore_file = path.join(".gitignore");
+        if options.respect_gitignore && gitignore_file.is_file() {
+            let (gitignore, error) = Gitignore::new(&gitignore_file);
+            if let Some(error) = error {
+                report.errors.push(error.to_string());
+            }
+            gitignores.push(gitignore);
+        }
+    }
+    report
+}
+
+fn is_ignored(root: &Path, path: &Path, patterns: &[Pattern], options: MatchOptions) -> bool {
+    let relative = path
+        .strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    patterns
+        .iter()
+        .any(|pattern| pattern.matches_with(&relative, options))
+}
+
+fn is_gitignored(gitignores: &[Gitignore], path: &Path) -> bool {
+    for gitignore in gitignores.iter().rev() {
+        match gitignore.matched(path, true) {
+            Match::Ignore(_) => return true,
+            Match::Whitelist(_) => return false,
+            Match::None => {}
+        }
+    }
+    false
+}
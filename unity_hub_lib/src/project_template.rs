@@ -1,8 +1,9 @@
 use dpc_pariter::IteratorExt;
 use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
 use std::path::Path;
 
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq)]
 pub struct ProjectTemplate {
     pub path: String,
     pub title: String,
@@ -14,16 +15,21 @@ impl PartialEq for ProjectTemplate {
     }
 }
 
+impl Hash for ProjectTemplate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+    }
+}
+
 impl ProjectTemplate {
-    pub fn find_templates(path: &str) -> Vec<ProjectTemplate> {
-        let dir = std::fs::read_dir(Path::new(&path).join(crate::consts::TEMPLATES_DIR));
+    pub fn find_templates(templates_dir: &Path) -> Vec<ProjectTemplate> {
+        let dir = std::fs::read_dir(templates_dir);
 
         if dir.is_err() {
             return Vec::new();
         }
 
         dir.unwrap()
-            .into_iter()
             .parallel_filter(|path| path.is_ok())
             .parallel_map(|path| path.unwrap())
             .parallel_filter(|path| path.file_name().into_string().unwrap().contains(".tgz"))
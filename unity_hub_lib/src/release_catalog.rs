@@ -0,0 +1,418 @@
+use crate::architecture::Architecture;
+use crate::editor_layout::EditorLayout;
+use crate::editor_module::{EditorModule, PathRename};
+use crate::unity_version::UnityVersion;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+/// Releases requested per page, the maximum accepted by Unity's API.
+const PAGE_SIZE: usize = 25;
+
+#[derive(Debug)]
+pub enum CatalogError {
+    Http(ureq::Error),
+    Parse(serde_json::Error),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::Http(err) => write!(f, "Failed to download release catalog: {}", err),
+            CatalogError::Parse(err) => write!(f, "Invalid release catalog: {}", err),
+            CatalogError::Io(err) => write!(f, "Failed to access release catalog cache: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+impl From<ureq::Error> for CatalogError {
+    fn from(err: ureq::Error) -> Self {
+        CatalogError::Http(err)
+    }
+}
+
+impl From<serde_json::Error> for CatalogError {
+    fn from(err: serde_json::Error) -> Self {
+        CatalogError::Parse(err)
+    }
+}
+
+impl From<std::io::Error> for CatalogError {
+    fn from(err: std::io::Error) -> Self {
+        CatalogError::Io(err)
+    }
+}
+
+/// Size as reported by the API, e.g. `{ "value": 1530, "unit": "MEGABYTE" }`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+pub struct ReleaseSize {
+    pub value: f64,
+    #[serde(default)]
+    pub unit: SizeUnit,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SizeUnit {
+    #[default]
+    Byte,
+    Kilobyte,
+    Megabyte,
+    Gigabyte,
+}
+
+impl ReleaseSize {
+    pub fn bytes(&self) -> u64 {
+        let multiplier = match self.unit {
+            SizeUnit::Byte => 1.0,
+            SizeUnit::Kilobyte => 1024.0,
+            SizeUnit::Megabyte => 1024.0 * 1024.0,
+            SizeUnit::Gigabyte => 1024.0 * 1024.0 * 1024.0,
+        };
+        (self.value * multiplier) as u64
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseModule {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub category: String,
+    pub url: String,
+    /// Subresource integrity string like `sha384-...`, missing for older releases.
+    #[serde(default)]
+    pub integrity: Option<String>,
+    #[serde(default)]
+    pub download_size: ReleaseSize,
+    #[serde(default)]
+    pub installed_size: ReleaseSize,
+    #[serde(default)]
+    pub hidden: bool,
+    /// Install location, usually starting with `{UNITY_PATH}`.
+    #[serde(default)]
+    pub destination: Option<String>,
+    #[serde(default)]
+    pub extracted_path_rename: Option<PathRename>,
+    #[serde(default)]
+    pub sub_modules: Vec<ReleaseModule>,
+}
+
+impl ReleaseModule {
+    /// Flattens the module and its sub modules into `modules.json` entries.
+    pub fn to_editor_modules(&self, parent: Option<&str>) -> Vec<EditorModule> {
+        let module = EditorModule {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            category: self.category.clone(),
+            visible: !self.hidden,
+            selected: false,
+            is_installed: Some(false),
+            download_url: Some(self.url.clone()),
+            download_size: self.download_size.bytes(),
+            installed_size: self.installed_size.bytes(),
+            checksum: self.integrity.clone(),
+            destination: self.destination.clone(),
+            parent: parent.map(str::to_string),
+            extracted_path_rename: self.extracted_path_rename.clone(),
+        };
+        let mut modules = vec![module];
+        for sub_module in &self.sub_modules {
+            modules.extend(sub_module.to_editor_modules(Some(&self.id)));
+        }
+        modules
+    }
+}
+
+/// Editor installer for a single OS and architecture.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseDownload {
+    pub url: String,
+    #[serde(default)]
+    pub integrity: Option<String>,
+    /// `WINDOWS`, `MAC_OS` or `LINUX`.
+    pub platform: String,
+    /// `X86_64` or `ARM64`.
+    #[serde(default)]
+    pub architecture: String,
+    #[serde(default)]
+    pub download_size: ReleaseSize,
+    #[serde(default)]
+    pub installed_size: ReleaseSize,
+    #[serde(default)]
+    pub modules: Vec<ReleaseModule>,
+}
+
+impl ReleaseDownload {
+    pub fn matches(&self, layout: EditorLayout, architecture: Architecture) -> bool {
+        let platform = match layout {
+            EditorLayout::Windows => "WINDOWS",
+            EditorLayout::MacOs => "MAC_OS",
+            EditorLayout::Linux => "LINUX",
+        };
+        let architecture = match architecture {
+            Architecture::Arm64 => "ARM64",
+            _ => "X86_64",
+        };
+        self.platform == platform
+            && (self.architecture.is_empty() || self.architecture == architecture)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorRelease {
+    pub version: String,
+    #[serde(default)]
+    pub short_revision: String,
+    #[serde(default)]
+    pub release_date: String,
+    /// `LTS`, `TECH`, `BETA` or `ALPHA`.
+    #[serde(default)]
+    pub stream: String,
+    #[serde(default)]
+    pub downloads: Vec<ReleaseDownload>,
+}
+
+impl EditorRelease {
+    pub fn unity_version(&self) -> Option<UnityVersion> {
+        UnityVersion::parse(&self.version)
+    }
+
+    /// Installer for the given OS layout and architecture, falls back to x86_64 on ARM machines.
+    pub fn download_for(
+        &self,
+        layout: EditorLayout,
+        architecture: Architecture,
+    ) -> Option<&ReleaseDownload> {
+        self.downloads
+            .iter()
+            .find(|download| download.matches(layout, architecture))
+            .or_else(|| {
+                self.downloads
+                    .iter()
+                    .find(|download| download.matches(layout, Architecture::X86_64))
+            })
+    }
+
+    /// Download for the machine the hub runs on.
+    pub fn host_download(&self) -> Option<&ReleaseDownload> {
+        self.download_for(EditorLayout::current(), Architecture::host())
+    }
+
+    /// Release date without the time part, e.g. `2023-09-26`.
+    pub fn date(&self) -> &str {
+        self.release_date
+            .split('T')
+            .next()
+            .unwrap_or(&self.release_date)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleasePage {
+    /// Missing in some mirrors, paging then stops at the first short page.
+    total: Option<usize>,
+    #[serde(default)]
+    results: Vec<EditorRelease>,
+}
+
+/// Reads pages returned by `load_page` for a release offset until all releases are received,
+/// newest release first.
+fn read_pages(
+    mut load_page: impl FnMut(usize) -> Result<String, CatalogError>,
+    mut progress: impl FnMut(usize, usize),
+) -> Result<Vec<EditorRelease>, CatalogError> {
+    let mut releases: Vec<EditorRelease> = Vec::new();
+    loop {
+        let page: ReleasePage = serde_json::from_str(&load_page(releases.len())?)?;
+        let received = page.results.len();
+        releases.extend(page.results);
+        let done = match page.total {
+            Some(total) => releases.len() >= total,
+            None => received < PAGE_SIZE,
+        };
+        progress(
+            releases.len(),
+            page.total.unwrap_or_default().max(releases.len()),
+        );
+        if received == 0 || done {
+            break;
+        }
+    }
+    releases.sort_by_key(|release| std::cmp::Reverse(release.unity_version()));
+    Ok(releases)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedCatalog {
+    url: String,
+    fetched_at: SystemTime,
+    releases: Vec<EditorRelease>,
+}
+
+/// Client of the editor release API, the endpoint can point at Unity, a mirror or a local server
+/// serving the same JSON pages.
+#[derive(Debug, Clone)]
+pub struct ReleaseCatalog {
+    pub base_url: String,
+    /// File where fetched releases are stored between runs, no caching when `None`.
+    pub cache_path: Option<PathBuf>,
+}
+
+impl ReleaseCatalog {
+    pub fn new(base_url: &str, cache_path: Option<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cache_path,
+        }
+    }
+
+    /// Releases from the cache written by `refresh`, if it was fetched from the same endpoint.
+    pub fn cached(&self) -> Option<(Vec<EditorRelease>, SystemTime)> {
+        let content = std::fs::read_to_string(self.cache_path.as_ref()?).ok()?;
+        let cache: CachedCatalog = serde_json::from_str(&content).ok()?;
+        (cache.url == self.base_url).then_some((cache.releases, cache.fetched_at))
+    }
+
+    /// True when there is no cache or it is older than `max_age`.
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        match self.cached() {
+            Some((_, fetched_at)) => fetched_at.elapsed().map_or(true, |age| age > max_age),
+            None => true,
+        }
+    }
+
+    /// Downloads all pages of the catalog, `progress` receives fetched and total release count.
+    pub fn fetch(
+        &self,
+        progress: impl FnMut(usize, usize),
+    ) -> Result<Vec<EditorRelease>, CatalogError> {
+        read_pages(
+            |offset| {
+                Ok(ureq::get(&self.base_url)
+                    .query("offset", offset.to_string())
+                    .query("limit", PAGE_SIZE.to_string())
+                    .call()?
+                    .body_mut()
+                    .read_to_string()?)
+            },
+            progress,
+        )
+    }
+
+    /// Fetches the catalog and stores it in the cache.
+    pub fn refresh(
+        &self,
+        progress: impl FnMut(usize, usize),
+    ) -> Result<Vec<EditorRelease>, CatalogError> {
+        let releases = self.fetch(progress)?;
+        if let Some(cache_path) = &self.cache_path {
+            if let Some(dir) = cache_path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let cache = CachedCatalog {
+                url: self.base_url.clone(),
+                fetched_at: SystemTime::now(),
+                releases,
+            };
+            std::fs::write(cache_path, serde_json::to_string(&cache)?)?;
+            return Ok(cache.releases);
+        }
+        Ok(releases)
+    }
+
+    /// Release with exactly this version, e.g. the one a project needs.
+    pub fn find<'a>(releases: &'a [EditorRelease], version: &str) -> Option<&'a EditorRelease> {
+        releases.iter().find(|release| release.version == version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURES: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/release_catalog"
+    );
+
+    /// Serves `page_<offset>.json` from the fixtures, recording requested offsets.
+    fn read_fixture_pages(offsets: &mut Vec<usize>) -> Result<Vec<EditorRelease>, CatalogError> {
+        read_pages(
+            |offset| {
+                offsets.push(offset);
+                Ok(std::fs::read_to_string(format!(
+                    "{}/page_{}.json",
+                    FIXTURES, offset
+                ))?)
+            },
+            |_, _| {},
+        )
+    }
+
+    #[test]
+    fn reads_all_pages_newest_first() {
+        let mut offsets = Vec::new();
+        let releases = read_fixture_pages(&mut offsets).unwrap();
+        assert_eq!(offsets, vec![0, 2]);
+        let versions: Vec<&str> = releases.iter().map(|r| r.version.as_str()).collect();
+        assert_eq!(versions, vec!["6000.0.23f1", "2022.3.10f1", "2021.3.5f1"]);
+
+        let download = &releases[0].downloads[0];
+        assert_eq!(download.platform, "LINUX");
+        assert_eq!(download.download_size.bytes(), 1530 * 1024 * 1024);
+        assert_eq!(download.modules[0].id, "android");
+        assert_eq!(
+            download.modules[0].sub_modules[0].id,
+            "android-sdk-ndk-tools"
+        );
+        assert_eq!(releases[0].date(), "2024-10-16");
+    }
+
+    #[test]
+    fn reports_progress_against_the_total() {
+        let mut reported = Vec::new();
+        read_pages(
+            |offset| {
+                Ok(std::fs::read_to_string(format!(
+                    "{}/page_{}.json",
+                    FIXTURES, offset
+                ))?)
+            },
+            |fetched, total| reported.push((fetched, total)),
+        )
+        .unwrap();
+        assert_eq!(reported, vec![(2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn stops_at_a_short_page_without_total() {
+        let mut offsets = Vec::new();
+        let releases = read_pages(
+            |offset| {
+                offsets.push(offset);
+                Ok(r#"{ "results": [{ "version": "2022.3.10f1" }] }"#.to_string())
+            },
+            |_, _| {},
+        )
+        .unwrap();
+        assert_eq!(offsets, vec![0]);
+        assert_eq!(releases.len(), 1);
+    }
+
+    #[test]
+    fn reports_invalid_pages() {
+        let result = read_pages(|_| Ok("<html>".to_string()), |_, _| {});
+        assert!(matches!(result, Err(CatalogError::Parse(_))));
+    }
+}
@@ -0,0 +1,225 @@
+use crate::paths;
+use glob::{MatchOptions, Pattern};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_DEPTH: usize = 2;
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct SearchPath {
+    /// Path as typed by the user, may contain `~` and environment variables.
+    pub path: String,
+    pub label: String,
+    pub depth: usize,
+    pub enabled: bool,
+    /// Glob patterns matched against the editor directory relative to `path`.
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+/// Outcome of the last scan of a single search path.
+#[derive(Debug, Clone, Default)]
+pub struct SearchPathReport {
+    pub editors: usize,
+    pub errors: Vec<String>,
+}
+
+impl SearchPath {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            label: String::new(),
+            depth: DEFAULT_DEPTH,
+            enabled: true,
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+
+    pub fn resolved(&self) -> PathBuf {
+        paths::expand(&self.path)
+    }
+
+    pub fn display_name(&self) -> &str {
+        if self.label.is_empty() {
+            &self.path
+        } else {
+            &self.label
+        }
+    }
+
+    /// Checks editor directory found under this search path against include and exclude patterns.
+    pub fn accepts(&self, editor_dir: &Path) -> bool {
+        let resolved = self.resolved();
+        let relative = editor_dir
+            .strip_prefix(&resolved)
+            .unwrap_or(editor_dir)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let options = MatchOptions {
+            case_sensitive: !cfg!(windows),
+            ..Default::default()
+        };
+        let matches = |patterns: &Vec<String>| {
+            patterns.iter().any(|pattern| {
+                Pattern::new(pattern).is_ok_and(|p| p.matches_with(&relative, options))
+            })
+        };
+
+        (self.include.is_empty() || matches(&self.include)) && !matches(&self.exclude)
+    }
+
+    /// Returns error messages for patterns that are not valid globs.
+    pub fn pattern_errors(&self) -> Vec<String> {
+        self.include
+            .iter()
+            .chain(self.exclude.iter())
+            .filter_map(|pattern| {
+                Pattern::new(pattern)
+                    .err()
+                    .map(|e| format!("Invalid pattern \"{}\": {}", pattern, e.msg))
+            })
+            .collect()
+    }
+}
+
+impl From<&str> for SearchPath {
+    fn from(path: &str) -> Self {
+        SearchPath::new(path)
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Older configs stored search paths as plain strings.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Plain(String),
+            Full {
+                path: String,
+                #[serde(default)]
+                label: String,
+                #[serde(default = "default_depth")]
+                depth: usize,
+                #[serde(default = "default_enabled")]
+                enabled: bool,
+                #[serde(default)]
+                include: Vec<String>,
+                #[serde(default)]
+                exclude: Vec<String>,
+            },
+        }
+        fn default_depth() -> usize {
+            DEFAULT_DEPTH
+        }
+        fn default_enabled() -> bool {
+            true
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Plain(path) => SearchPath::new(&path),
+            Repr::Full {
+                path,
+                label,
+                depth,
+                enabled,
+                include,
+                exclude,
+            } => SearchPath {
+                path,
+                label,
+                depth,
+                enabled,
+                include,
+                exclude,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Config {
+        search_paths: Vec<SearchPath>,
+    }
+
+    fn with_patterns(include: &[&str], exclude: &[&str]) -> SearchPath {
+        SearchPath {
+            include: include.iter().map(|p| p.to_string()).collect(),
+            exclude: exclude.iter().map(|p| p.to_string()).collect(),
+            ..SearchPath::new("/opt/unity")
+        }
+    }
+
+    #[test]
+    fn migrates_legacy_string_paths() {
+        let config: Config =
+            toml::from_str(r#"search_paths = ["/opt/unity", "~/Unity/Hub/Editor"]"#).unwrap();
+        assert_eq!(
+            config.search_paths,
+            vec![
+                SearchPath::new("/opt/unity"),
+                SearchPath::new("~/Unity/Hub/Editor")
+            ]
+        );
+        assert_eq!(config.search_paths[0].depth, DEFAULT_DEPTH);
+        assert!(config.search_paths[0].enabled);
+    }
+
+    #[test]
+    fn reads_full_and_legacy_paths_together() {
+        let config: Config = toml::from_str(
+            r#"
+            search_paths = [
+                "/opt/unity",
+                { path = "/mnt/editors", label = "Shared", depth = 1, enabled = false, exclude = ["*beta*"] },
+            ]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.search_paths[0], SearchPath::new("/opt/unity"));
+        let full = &config.search_paths[1];
+        assert_eq!(full.path, "/mnt/editors");
+        assert_eq!(full.label, "Shared");
+        assert_eq!(full.depth, 1);
+        assert!(!full.enabled);
+        assert!(full.include.is_empty());
+        assert_eq!(full.exclude, vec!["*beta*".to_string()]);
+    }
+
+    #[test]
+    fn accepts_everything_without_patterns() {
+        let search_path = SearchPath::new("/opt/unity");
+        assert!(search_path.accepts(Path::new("/opt/unity/2022.3.10f1")));
+    }
+
+    #[test]
+    fn accepts_only_included_directories() {
+        let search_path = with_patterns(&["2022.*", "6000.*"], &[]);
+        assert!(search_path.accepts(Path::new("/opt/unity/2022.3.10f1")));
+        assert!(search_path.accepts(Path::new("/opt/unity/6000.0.23f1")));
+        assert!(!search_path.accepts(Path::new("/opt/unity/2021.3.5f1")));
+    }
+
+    #[test]
+    fn rejects_excluded_directories() {
+        let search_path = with_patterns(&[], &["*b*"]);
+        assert!(search_path.accepts(Path::new("/opt/unity/2022.3.10f1")));
+        assert!(!search_path.accepts(Path::new("/opt/unity/2023.1.0b5")));
+    }
+
+    #[test]
+    fn exclude_wins_over_include() {
+        let search_path = with_patterns(&["2023.*"], &["*b*"]);
+        assert!(search_path.accepts(Path::new("/opt/unity/2023.2.1f1")));
+        assert!(!search_path.accepts(Path::new("/opt/unity/2023.1.0b5")));
+        assert!(!search_path.accepts(Path::new("/opt/unity/2022.3.10f1")));
+    }
+}
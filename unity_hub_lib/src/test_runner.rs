@@ -0,0 +1,361 @@
+use crate::batch::BatchJob;
+use crate::unity_editor::UnityEditor;
+use crate::unity_project::UnityProject;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TestPlatform {
+    EditMode,
+    PlayMode,
+}
+
+impl TestPlatform {
+    pub const ALL: [TestPlatform; 2] = [TestPlatform::EditMode, TestPlatform::PlayMode];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TestPlatform::EditMode => "EditMode",
+            TestPlatform::PlayMode => "PlayMode",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum TestOutcome {
+    Passed,
+    Failed,
+    Skipped,
+    Inconclusive,
+}
+
+impl TestOutcome {
+    fn parse(result: &str) -> Self {
+        match result {
+            "Passed" => TestOutcome::Passed,
+            "Failed" => TestOutcome::Failed,
+            "Skipped" | "Ignored" => TestOutcome::Skipped,
+            _ => TestOutcome::Inconclusive,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TestCase {
+    pub name: String,
+    pub full_name: String,
+    pub outcome: TestOutcome,
+    /// Seconds.
+    pub duration: f64,
+    /// Failure or skip reason.
+    pub message: Option<String>,
+    pub stack_trace: Option<String>,
+    /// Text the test wrote to the log.
+    pub output: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TestSuite {
+    pub name: String,
+    pub full_name: String,
+    pub outcome: TestOutcome,
+    pub duration: f64,
+    pub suites: Vec<TestSuite>,
+    pub cases: Vec<TestCase>,
+}
+
+/// Parsed NUnit 3 `test-run` element.
+#[derive(Debug, Serialize, Clone)]
+pub struct TestRun {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub inconclusive: usize,
+    pub duration: f64,
+    pub suites: Vec<TestSuite>,
+}
+
+#[derive(Debug)]
+pub enum TestResultsError {
+    Io(std::io::Error),
+    Xml(roxmltree::Error),
+    /// The document has no `test-run` root element.
+    NotNUnit,
+}
+
+impl fmt::Display for TestResultsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TestResultsError::Io(err) => write!(f, "Failed to read test results: {}", err),
+            TestResultsError::Xml(err) => write!(f, "Invalid test results: {}", err),
+            TestResultsError::NotNUnit => write!(f, "Test results are not in NUnit 3 format"),
+        }
+    }
+}
+
+impl std::error::Error for TestResultsError {}
+
+impl TestSuite {
+    /// Failed test cases of this suite and all nested suites.
+    pub fn failures(&self) -> Vec<&TestCase> {
+        let mut failures: Vec<&TestCase> = self
+            .cases
+            .iter()
+            .filter(|case| case.outcome == TestOutcome::Failed)
+            .collect();
+        for suite in &self.suites {
+            failures.extend(suite.failures());
+        }
+        failures
+    }
+}
+
+impl TestRun {
+    pub fn succeeded(&self) -> bool {
+        self.failed == 0
+    }
+
+    pub fn failures(&self) -> Vec<&TestCase> {
+        self.suites.iter().flat_map(TestSuite::failures).collect()
+    }
+}
+
+/// Batch job running the tests of `project`, results are written to `Logs/TestResults-*.xml`.
+/// `filter` limits the run to tests which full name matches, as `-testFilter` does.
+pub fn test_job(
+    editor: &UnityEditor,
+    project: &UnityProject,
+    platform: TestPlatform,
+    filter: Option<&str>,
+) -> BatchJob {
+    let mut job = BatchJob::new(editor, project);
+    // `-runTests` quits on its own, `-quit` would stop the editor before the tests run.
+    job.quit = false;
+    job.title = format!("{} ({} tests)", project.title, platform.name());
+    let results = results_path(&job);
+    let mut args = vec![
+        "-runTests".to_string(),
+        "-testPlatform".to_string(),
+        platform.name().to_string(),
+        "-testResults".to_string(),
+        results,
+    ];
+    if let Some(filter) = filter.filter(|filter| !filter.is_empty()) {
+        args.extend(["-testFilter".to_string(), filter.to_string()]);
+    }
+    job.with_args(args)
+}
+
+/// Path of the results file written by a job made with `test_job`.
+pub fn results_path(job: &BatchJob) -> String {
+    job.log_file
+        .with_file_name(format!(
+            "TestResults-{}.xml",
+            job.log_file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default()
+        ))
+        .to_string_lossy()
+        .to_string()
+}
+
+pub fn read_results(path: &Path) -> Result<TestRun, TestResultsError> {
+    let content = std::fs::read_to_string(path).map_err(TestResultsError::Io)?;
+    parse_results(&content)
+}
+
+pub fn parse_results(xml: &str) -> Result<TestRun, TestResultsError> {
+    let document = roxmltree::Document::parse(xml).map_err(TestResultsError::Xml)?;
+    let root = document.root_element();
+    if !root.has_tag_name("test-run") {
+        return Err(TestResultsError::NotNUnit);
+    }
+    let count = |name: &str| {
+        root.attribute(name)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0)
+    };
+    Ok(TestRun {
+        total: count("total"),
+        passed: count("passed"),
+        failed: count("failed"),
+        skipped: count("skipped"),
+        inconclusive: count("inconclusive"),
+        duration: duration(root),
+        suites: root
+            .children()
+            .filter(|node| node.has_tag_name("test-suite"))
+            .map(parse_suite)
+            .collect(),
+    })
+}
+
+fn parse_suite(node: roxmltree::Node) -> TestSuite {
+    TestSuite {
+        name: node.attribute("name").unwrap_or_default().to_string(),
+        full_name: node.attribute("fullname").unwrap_or_default().to_string(),
+        outcome: TestOutcome::parse(node.attribute("result").unwrap_or_default()),
+        duration: duration(node),
+        suites: node
+            .children()
+            .filter(|child| child.has_tag_name("test-suite"))
+            .map(parse_suite)
+            .collect(),
+        cases: node
+            .children()
+            .filter(|child| child.has_tag_name("test-case"))
+            .map(parse_case)
+            .collect(),
+    }
+}
+
+fn parse_case(node: roxmltree::Node) -> TestCase {
+    let failure = child(node, "failure").or_else(|| child(node, "reason"));
+    TestCase {
+        name: node.attribute("name").unwrap_or_default().to_string(),
+        full_name: node.attribute("fullname").unwrap_or_default().to_string(),
+        outcome: TestOutcome::parse(node.attribute("result").unwrap_or_default()),
+        duration: duration(node),
+        message: failure.and_then(|failure| text_of(failure, "message")),
+        stack_trace: failure.and_then(|failure| text_of(failure, "stack-trace")),
+        output: text_of(node, "output"),
+    }
+}
+
+fn child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(name))
+}
+
+/// Trimmed text of a child element, CDATA sections included.
+fn text_of(node: roxmltree::Node, name: &str) -> Option<String> {
+    let text: String = child(node, name)?
+        .children()
+        .filter_map(|child| child.text())
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn duration(node: roxmltree::Node) -> f64 {
+    node.attribute("duration")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/test_runner/TestResults.xml"
+    );
+
+    fn run() -> TestRun {
+        read_results(Path::new(FIXTURE)).unwrap()
+    }
+
+    #[test]
+    fn reads_run_totals() {
+        let run = run();
+        assert_eq!(
+            (
+                run.total,
+                run.passed,
+                run.failed,
+                run.skipped,
+                run.inconclusive
+            ),
+            (3, 1, 1, 1, 0)
+        );
+        assert_eq!(run.duration, 0.8123);
+        assert!(!run.succeeded());
+    }
+
+    #[test]
+    fn keeps_nested_suites() {
+        let run = run();
+        let assembly = &run.suites[0].suites[0];
+        assert_eq!(assembly.name, "Game.Tests.dll");
+        let fixture = &assembly.suites[0];
+        assert_eq!(fixture.full_name, "Game.Tests.InventoryTests");
+        assert_eq!(fixture.outcome, TestOutcome::Failed);
+        let outcomes: Vec<TestOutcome> = fixture.cases.iter().map(|case| case.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                TestOutcome::Passed,
+                TestOutcome::Failed,
+                TestOutcome::Skipped
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_failure_message_and_stack_trace_from_cdata() {
+        let run = run();
+        let failures = run.failures();
+        assert_eq!(failures.len(), 1);
+        let failure = failures[0];
+        assert_eq!(failure.full_name, "Game.Tests.InventoryTests.RemovesItem");
+        assert_eq!(
+            failure.message.as_deref(),
+            Some("Expected: 0\n  But was:  1")
+        );
+        assert_eq!(
+            failure.stack_trace.as_deref(),
+            Some("at Game.Tests.InventoryTests.RemovesItem () [0x00012] in /Projects/Game/Assets/Tests/InventoryTests.cs:27")
+        );
+    }
+
+    #[test]
+    fn reads_skip_reason_and_output() {
+        let run = run();
+        let cases = &run.suites[0].suites[0].suites[0].cases;
+        assert_eq!(cases[0].output.as_deref(), Some("Added Sword"));
+        assert_eq!(cases[0].message, None);
+        assert_eq!(cases[2].message.as_deref(), Some("Needs a save folder"));
+    }
+
+    #[test]
+    fn rejects_other_documents() {
+        assert!(matches!(
+            parse_results("<testsuites></testsuites>"),
+            Err(TestResultsError::NotNUnit)
+        ));
+        assert!(matches!(
+            parse_results("<test-run"),
+            Err(TestResultsError::Xml(_))
+        ));
+    }
+
+    #[test]
+    fn results_file_follows_the_log_name() {
+        let mut job = BatchJob {
+            title: String::new(),
+            editor_exe: String::new(),
+            project_path: String::new(),
+            execute_method: None,
+            args: Vec::new(),
+            log_file: Path::new("Game")
+                .join("Logs")
+                .join("batch-1700000000000-3.log"),
+            nographics: true,
+            quit: false,
+        };
+        assert_eq!(
+            Path::new(&results_path(&job)),
+            Path::new("Game")
+                .join("Logs")
+                .join("TestResults-batch-1700000000000-3.xml")
+        );
+        job.log_file = job.log_file.with_file_name("batch-1700000000000-4.log");
+        assert!(results_path(&job).ends_with("TestResults-batch-1700000000000-4.xml"));
+    }
+}
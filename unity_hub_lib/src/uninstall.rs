@@ -0,0 +1,237 @@
+use crate::config::Configuration;
+use crate::editor_layout::EditorLayout;
+use crate::unity_editor::UnityEditor;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use walkdir::WalkDir;
+
+#[derive(Debug)]
+pub enum UninstallError {
+    /// Path doesn't look like an editor installation, nothing was removed.
+    NotAnEditor(PathBuf),
+    /// Executable of a running process lives inside the installation.
+    Running(PathBuf),
+    /// The installation folder holds something that isn't part of the editor, e.g. another
+    /// editor, nothing was removed.
+    ForeignContent(PathBuf),
+    Io(PathBuf, std::io::Error),
+}
+
+impl fmt::Display for UninstallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UninstallError::NotAnEditor(path) => {
+                write!(f, "{} is not a Unity editor directory", path.display())
+            }
+            UninstallError::Running(path) => {
+                write!(f, "{} is running, close it first", path.display())
+            }
+            UninstallError::ForeignContent(path) => write!(
+                f,
+                "{} is not part of the editor, move it out of the installation first",
+                path.display()
+            ),
+            UninstallError::Io(path, err) => {
+                write!(f, "Failed to remove {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for UninstallError {}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UninstallProgress {
+    pub removed_files: usize,
+    pub total_files: usize,
+}
+
+/// What uninstalling an editor removes.
+#[derive(Debug, Clone)]
+pub struct InstallFiles {
+    /// Folder of the installation, Unity Hub installs own the `<version>` folder containing
+    /// `Editor` and `modules.json`.
+    pub root: PathBuf,
+    /// Files and folders removed with everything inside.
+    pub entries: Vec<PathBuf>,
+    /// Folders removed afterwards, only when nothing else is left in them.
+    pub dirs: Vec<PathBuf>,
+}
+
+/// Folders shipped next to the executable. Files next to it are the editor's own libraries,
+/// any other folder could be another editor.
+fn editor_dirs(layout: EditorLayout) -> &'static [&'static str] {
+    match layout {
+        EditorLayout::Windows | EditorLayout::Linux => &["Data", "BugReporter"],
+        // Loose files aren't part of a macOS install, everything lives in bundles or folders.
+        EditorLayout::MacOs => &[
+            "Unity.app",
+            "PlaybackEngines",
+            "Documentation",
+            "Unity Bug Reporter.app",
+            "MonoDevelop.app",
+            "Standard Assets",
+        ],
+    }
+}
+
+/// Lists the editor files, refusing installs that share their folder with anything else.
+pub fn install_files(editor: &UnityEditor) -> Result<InstallFiles, UninstallError> {
+    let base_path = Path::new(&editor.base_path);
+    let layout = editor.layout;
+    let dir = std::fs::read_dir(base_path)
+        .map_err(|err| UninstallError::Io(base_path.to_path_buf(), err))?;
+    let mut entries = Vec::new();
+    for entry in dir.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        // Links count as files, only the link is removed.
+        let is_dir = entry.file_type().is_ok_and(|kind| kind.is_dir());
+        let known = editor_dirs(layout).contains(&name.as_str())
+            || name == "modules.json"
+            || (!is_dir && layout != EditorLayout::MacOs);
+        if !known {
+            return Err(UninstallError::ForeignContent(path));
+        }
+        entries.push(path);
+    }
+    let mut files = InstallFiles {
+        root: base_path.to_path_buf(),
+        entries,
+        dirs: vec![base_path.to_path_buf()],
+    };
+
+    let Some(parent) = base_path.parent() else {
+        return Ok(files);
+    };
+    let hub_install = base_path.file_name().is_some_and(|name| name == "Editor")
+        && parent.join("modules.json").is_file();
+    if hub_install {
+        let dir = std::fs::read_dir(parent)
+            .map_err(|err| UninstallError::Io(parent.to_path_buf(), err))?;
+        for entry in dir.flatten() {
+            let name = entry.file_name();
+            if name != "Editor" && name != "modules.json" {
+                return Err(UninstallError::ForeignContent(entry.path()));
+            }
+        }
+        files.entries.push(parent.join("modules.json"));
+        files.dirs.push(parent.to_path_buf());
+        files.root = parent.to_path_buf();
+    }
+    Ok(files)
+}
+
+/// First running process started from an executable inside `dir`.
+pub fn running_process_in(dir: &Path) -> Option<PathBuf> {
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+    system
+        .processes()
+        .values()
+        .filter_map(|process| process.exe())
+        .find(|exe| exe.starts_with(&dir))
+        .map(Path::to_path_buf)
+}
+
+/// Removes the editor installation file by file, reporting progress after every file.
+pub fn uninstall(
+    editor: &UnityEditor,
+    mut progress: impl FnMut(UninstallProgress),
+) -> Result<(), UninstallError> {
+    let base_path = Path::new(&editor.base_path);
+    if !Configuration::is_unity_dir(base_path) {
+        return Err(UninstallError::NotAnEditor(base_path.to_path_buf()));
+    }
+    let install = install_files(editor)?;
+    if let Some(exe) = running_process_in(&install.root) {
+        return Err(UninstallError::Running(exe));
+    }
+
+    // Links are removed without following them, `Data` linked to a shared folder must not
+    // empty that folder.
+    let files: Vec<PathBuf> = install
+        .entries
+        .iter()
+        .flat_map(|entry| WalkDir::new(entry).follow_root_links(false))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| !entry.file_type().is_dir())
+        .map(|entry| entry.into_path())
+        .collect();
+    let mut state = UninstallProgress {
+        removed_files: 0,
+        total_files: files.len(),
+    };
+    progress(state);
+
+    for file in files {
+        remove_file(&file).map_err(|err| UninstallError::Io(file.clone(), err))?;
+        state.removed_files += 1;
+        progress(state);
+    }
+    for entry in install.entries.iter().filter(|entry| is_real_dir(entry)) {
+        std::fs::remove_dir_all(entry).map_err(|err| UninstallError::Io(entry.clone(), err))?;
+    }
+    for dir in install.dirs {
+        // Something was added meanwhile, keep it.
+        let _ = std::fs::remove_dir(dir);
+    }
+    Ok(())
+}
+
+fn is_real_dir(path: &Path) -> bool {
+    std::fs::symlink_metadata(path).is_ok_and(|metadata| metadata.is_dir())
+}
+
+/// Read-only files can't be removed on Windows, editor installs contain plenty of them.
+fn remove_file(path: &Path) -> std::io::Result<()> {
+    // Links to folders are removed as folders on Windows, the target stays untouched.
+    #[cfg(windows)]
+    if std::fs::symlink_metadata(path)?.file_type().is_symlink() && path.is_dir() {
+        return std::fs::remove_dir(path);
+    }
+    match std::fs::remove_file(path) {
+        Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+            let mut permissions = std::fs::symlink_metadata(path)?.permissions();
+            #[allow(clippy::permissions_set_readonly_false)]
+            permissions.set_readonly(false);
+            std::fs::set_permissions(path, permissions)?;
+            std::fs::remove_file(path)
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn removes_linked_folders_without_their_content() {
+        let dir = std::env::temp_dir().join(format!("rusty_hub_uninstall_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let editor_dir = dir.join("2022.3.10f1");
+        let shared = dir.join("shared");
+        std::fs::create_dir_all(&editor_dir).unwrap();
+        std::fs::create_dir_all(shared.join("PlaybackEngines")).unwrap();
+        std::fs::write(shared.join("PlaybackEngines").join("keep.txt"), b"keep").unwrap();
+        std::fs::write(editor_dir.join("Unity"), b"").unwrap();
+        std::os::unix::fs::symlink(&shared, editor_dir.join("Data")).unwrap();
+
+        let editor = UnityEditor::new(&editor_dir.to_string_lossy()).unwrap();
+        let mut removed = 0;
+        uninstall(&editor, |progress| removed = progress.removed_files).unwrap();
+
+        assert_eq!(removed, 2);
+        assert!(!editor_dir.exists());
+        assert!(shared.join("PlaybackEngines").join("keep.txt").is_file());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,18 +1,36 @@
-use crate::consts;
+use crate::architecture::Architecture;
+use crate::editor_layout::EditorLayout;
+use crate::editor_module::EditorModule;
+use crate::editor_version;
+use crate::fingerprint::Fingerprint;
 use crate::project_template::ProjectTemplate;
+use crate::unity_version::UnityVersion;
 use serde::{Deserialize, Serialize};
-use std::borrow::Borrow;
-use std::collections::HashMap;
-use std::hash::Hash;
-use std::path::Path;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
 
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq)]
 pub struct UnityEditor {
     pub version: String,
     pub exe_path: String,
     pub base_path: String,
     pub platforms: Vec<String>,
     pub templates: Vec<ProjectTemplate>,
+    #[serde(default)]
+    pub modules: Vec<EditorModule>,
+    #[serde(default)]
+    pub layout: EditorLayout,
+    #[serde(default)]
+    pub architecture: Architecture,
+    #[serde(default)]
+    pub install_date: Option<SystemTime>,
+    /// Size of the whole installation in bytes, computed separately with `compute_installed_size`.
+    #[serde(default)]
+    pub installed_size: Option<u64>,
+    #[serde(default)]
+    pub fingerprint: Fingerprint,
 }
 
 impl PartialEq for UnityEditor {
@@ -21,102 +39,162 @@ impl PartialEq for UnityEditor {
     }
 }
 
+impl Hash for UnityEditor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.exe_path.hash(state);
+    }
+}
+
 impl UnityEditor {
     pub fn new(path: &str) -> Option<Self> {
         let base_path = Path::new(path);
-        let exe_path = base_path.join(consts::UNITY_EXE_NAME);
-        let meta = std::fs::metadata(&exe_path);
-        if !meta.is_ok_and(|meta| meta.is_file()) {
-            return None;
-        }
+        let layout = EditorLayout::detect(base_path)?;
+        let exe_path = layout.exe_path(base_path);
 
-        let mut version: Option<String> = None;
-
-        #[cfg(windows)]
-        {
-            use exe::pe::VecPE;
-            use exe::VSVersionInfo;
-            let image = VecPE::from_disk_file(&exe_path).unwrap();
-            let vs_version_check = VSVersionInfo::parse(&image);
-
-            if let Some(string_file_info) = vs_version_check.unwrap().string_file_info {
-                let hashmap_result = string_file_info.children[0].string_map();
-
-                let hash_map = match hashmap_result {
-                    Ok(r) => r,
-                    Err(_) => HashMap::new(),
-                };
-
-                if let Some(result_version) = hash_map.get("ProductVersion") {
-                    version = Some(result_version.clone());
-                    if let Some(short) = result_version.clone().split("_").take(1).next() {
-                        version = Some(short.to_string());
-                    }
-                }
-            }
-        }
+        let mut version = editor_version::detect(base_path, layout);
         if version.is_none() {
+            // Unity Hub installs editors as `<version>/Editor`, so skip that part of the path.
             let folder = base_path
-                .to_str()
-                .expect("Fail")
-                .split(consts::SLASH)
-                .last()
-                .unwrap();
+                .components()
+                .rev()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .find(|name| name != "Editor")
+                .unwrap_or_default();
             version = Some(folder.to_string());
         }
 
-        if version.is_none() {
-            return None;
-        }
+        let modules = EditorModule::read(base_path);
+        // Editors without a readable `modules.json` only have their folders to go by.
+        let platforms = if modules.is_empty() {
+            UnityEditor::get_platforms(base_path, layout)
+        } else {
+            UnityEditor::get_platforms_from_modules(&modules)
+        };
+
+        let architecture = Architecture::detect(&exe_path);
+        let install_date = std::fs::metadata(base_path)
+            .and_then(|meta| meta.created())
+            .or_else(|_| std::fs::metadata(&exe_path).and_then(|meta| meta.modified()))
+            .ok();
+
         Some(Self {
-            version: version.unwrap().clone(),
+            version: version?,
             exe_path: exe_path.into_os_string().into_string().unwrap(),
             base_path: String::from(path),
-            platforms: UnityEditor::get_platforms(&path),
-            templates: ProjectTemplate::find_templates(&path),
+            platforms,
+            templates: ProjectTemplate::find_templates(&layout.templates_dir(base_path)),
+            modules,
+            layout,
+            architecture,
+            install_date,
+            installed_size: None,
+            fingerprint: Fingerprint::new(UnityEditor::fingerprint_paths(base_path, layout)),
         })
     }
 
-    fn get_platforms(unity_folder: &str) -> Vec<String> {
-        let platform_names = HashMap::from([
-            ("androidplayer", "Android"),
-            ("windowsstandalonesupport", "Windows"),
-            ("linuxstandalonesupport", "Linux"),
-            ("LinuxStandalone", "Linux"),
-            ("OSXStandalone", "OSX"),
-            ("webglsupport", "WebGL"),
-            ("metrosupport", "UWP"),
-            ("iossupport", "iOS"),
-        ]);
-
-        let mut platforms = Vec::new();
-        let base_path = Path::new(unity_folder).join("Data").join("PlaybackEngines");
-
-        if std::fs::metadata(&base_path).is_err() {
-            return platforms;
+    /// Files and folders the editor information is read from, see `is_up_to_date`.
+    fn fingerprint_paths(base_path: &Path, layout: EditorLayout) -> Vec<PathBuf> {
+        let mut paths = vec![
+            layout.exe_path(base_path),
+            base_path.join("modules.json"),
+            layout.playback_engines_dir(base_path),
+            layout.templates_dir(base_path),
+        ];
+        if let Some(parent) = base_path.parent() {
+            paths.push(parent.join("modules.json"));
         }
-        let dir = std::fs::read_dir(base_path);
+        paths
+    }
 
-        if dir.is_err() {
-            return platforms;
-        }
+    /// True when nothing the editor was read from changed since, so it doesn't need parsing again.
+    pub fn is_up_to_date(&self) -> bool {
+        self.fingerprint.is_current()
+    }
 
-        for path in dir.unwrap() {
-            if path.is_err() {
-                continue;
-            }
-            let f = path.unwrap();
-            if let Ok(result_string) = f.file_name().into_string() {
-                if let Some(value) =
-                    platform_names.get(&result_string.clone().to_lowercase().borrow())
-                {
-                    platforms.push(value.to_string());
-                } else {
-                    platforms.push(result_string);
-                }
-            }
-        }
+    pub fn unity_version(&self) -> Option<UnityVersion> {
+        UnityVersion::parse(&self.version)
+    }
+
+    /// Sums sizes of all files of the installation, slow for big editors.
+    pub fn compute_installed_size(base_path: &str) -> u64 {
+        WalkDir::new(base_path)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| entry.metadata().ok())
+            .map(|meta| meta.len())
+            .sum()
+    }
+
+    fn get_platforms_from_modules(modules: &[EditorModule]) -> Vec<String> {
+        modules
+            .iter()
+            .filter(|module| module.is_platform() && module.installed())
+            .map(EditorModule::platform_name)
+            .collect()
+    }
+
+    /// Installed build support of editors without `modules.json`, named like the modules.
+    fn get_platforms(base_path: &Path, layout: EditorLayout) -> Vec<String> {
+        let Ok(dir) = std::fs::read_dir(layout.playback_engines_dir(base_path)) else {
+            return Vec::new();
+        };
+        dir.flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .map(|folder| platform_name(&folder))
+            .collect()
+    }
+}
+
+/// Platform name of a `PlaybackEngines` folder, unknown folders keep their name.
+fn platform_name(folder: &str) -> String {
+    const PLATFORM_NAMES: [(&str, &str); 11] = [
+        ("androidplayer", "Android"),
+        ("windowsstandalonesupport", "Windows"),
+        ("linuxstandalonesupport", "Linux"),
+        ("linuxstandalone", "Linux"),
+        ("macstandalonesupport", "Mac"),
+        ("osxstandalone", "Mac"),
+        ("webglsupport", "WebGL"),
+        ("metrosupport", "Universal Windows Platform"),
+        ("iossupport", "iOS"),
+        ("appletvsupport", "tvOS"),
+        ("visionossupport", "visionOS"),
+    ];
+    let lower = folder.to_lowercase();
+    PLATFORM_NAMES
+        .iter()
+        .find(|(name, _)| *name == lower)
+        .map_or_else(|| folder.to_string(), |(_, platform)| platform.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_playback_engine_folders_like_modules() {
+        assert_eq!(platform_name("AndroidPlayer"), "Android");
+        assert_eq!(platform_name("iOSSupport"), "iOS");
+        assert_eq!(platform_name("WebGLSupport"), "WebGL");
+        assert_eq!(platform_name("SwitchPlayer"), "SwitchPlayer");
+    }
+
+    #[test]
+    fn falls_back_to_playback_engines_for_unreadable_modules_json() {
+        let dir = std::env::temp_dir().join(format!("rusty_hub_platforms_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let engines = dir.join("Data").join("PlaybackEngines");
+        std::fs::create_dir_all(engines.join("AndroidPlayer")).unwrap();
+        std::fs::create_dir_all(engines.join("iOSSupport")).unwrap();
+        std::fs::write(dir.join("Unity"), b"").unwrap();
+        std::fs::write(dir.join("modules.json"), b"[{\"id\": ").unwrap();
 
-        platforms
+        let editor = UnityEditor::new(&dir.to_string_lossy()).unwrap();
+        let mut platforms = editor.platforms;
+        platforms.sort();
+        assert_eq!(platforms, ["Android", "iOS"]);
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
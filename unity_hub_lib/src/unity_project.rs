@@ -1,9 +1,14 @@
+use crate::build_profile::BuildProfile;
+use crate::fingerprint::Fingerprint;
 use serde::{Deserialize, Serialize};
-use std::{ops::Sub, path::Path, str};
+use std::hash::{Hash, Hasher};
+use std::{
+    ops::Sub,
+    path::{Path, PathBuf},
+    str,
+};
 
-use crate::consts;
-
-#[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq)]
 pub struct UnityProject {
     pub path: String,
     pub title: String,
@@ -11,6 +16,19 @@ pub struct UnityProject {
     pub branch: String,
     pub is_valid: bool,
     pub edit_time: std::time::SystemTime,
+    #[serde(default)]
+    pub fingerprint: Fingerprint,
+    /// `productName` from the project settings, used to find the project after it was moved.
+    #[serde(default)]
+    pub product_name: String,
+    /// URL of the `origin` remote of the project repository.
+    #[serde(default)]
+    pub git_remote: String,
+    /// Hidden projects are only listed on demand.
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub build_profiles: Vec<BuildProfile>,
 }
 
 impl PartialEq for UnityProject {
@@ -19,6 +37,12 @@ impl PartialEq for UnityProject {
     }
 }
 
+impl Hash for UnityProject {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+    }
+}
+
 impl UnityProject {
     #[cfg(not(target_os = "windows"))]
     pub fn get_projects_from_registry() -> Vec<UnityProject> {
@@ -69,13 +93,10 @@ impl UnityProject {
                 .join("ProjectSettings")
                 .join("ProjectVersion.txt"),
         );
-        if project_version_file.is_err() {
-            return None;
-        }
-        let binding = project_version_file.unwrap();
+        let binding = project_version_file.ok()?;
         let mut iter = binding.split_whitespace();
         iter.next();
-        let project_version = iter.next().unwrap().to_string();
+        let project_version = iter.next()?.to_string();
 
         Some(project_version)
     }
@@ -91,12 +112,20 @@ impl UnityProject {
 
         let mut project = UnityProject {
             path: path.clone(),
-            title: path.split(consts::SLASH).last().unwrap().to_string(),
+            title: Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.clone()),
             branch: String::new(),
             version: String::new(),
             is_valid: true,
             edit_time: std::time::SystemTime::now()
                 .sub(std::time::Duration::new(60 * 60 * 24 * 365 * 30, 0)),
+            fingerprint: Fingerprint::default(),
+            product_name: String::new(),
+            git_remote: String::new(),
+            hidden: false,
+            build_profiles: Vec::new(),
         };
 
         project.update_info();
@@ -104,6 +133,17 @@ impl UnityProject {
         Some(project)
     }
 
+    /// Copies what `update_info` read from disk, leaving user settings like `hidden` alone.
+    pub fn merge_scanned(&mut self, scanned: UnityProject) {
+        self.version = scanned.version;
+        self.branch = scanned.branch;
+        self.is_valid = scanned.is_valid;
+        self.edit_time = scanned.edit_time;
+        self.fingerprint = scanned.fingerprint;
+        self.product_name = scanned.product_name;
+        self.git_remote = scanned.git_remote;
+    }
+
     pub fn update_info(&mut self) {
         let is_project = UnityProject::is_project_at_path(&self.path);
         self.is_valid = is_project;
@@ -113,15 +153,14 @@ impl UnityProject {
         }
 
         let mut base_path = Path::new(&self.path);
-        self.version = Self::get_version_at_path(&self.path).unwrap();
+        self.version = Self::get_version_at_path(&self.path).unwrap_or_default();
 
         match self.try_read_from_path(base_path) {
             None => {
                 while let Some(path) = base_path.parent() {
                     base_path = path;
-                    let new_branch = self.try_read_from_path(base_path);
-                    if new_branch.is_some() {
-                        self.branch = new_branch.unwrap();
+                    if let Some(new_branch) = self.try_read_from_path(base_path) {
+                        self.branch = new_branch;
                         break;
                     }
                 }
@@ -136,6 +175,53 @@ impl UnityProject {
                 self.edit_time = data;
             }
         }
+
+        let path = Path::new(&self.path);
+        let settings = path.join("ProjectSettings").join("ProjectSettings.asset");
+        if let Some(name) = read_yaml_value(&settings, "productName") {
+            self.product_name = name;
+        }
+        if let Some(remote) = read_origin_url(&base_path.join(".git").join("config")) {
+            self.git_remote = remote;
+        }
+        self.fingerprint = Fingerprint::new([
+            path.to_path_buf(),
+            path.join("ProjectSettings").join("ProjectVersion.txt"),
+            settings,
+            base_path.join(".git").join("HEAD"),
+        ]);
+    }
+
+    /// `HEAD` file of the git repository the project is in, if any.
+    pub fn git_head_path(&self) -> Option<PathBuf> {
+        Path::new(&self.path)
+            .ancestors()
+            .map(|dir| dir.join(".git").join("HEAD"))
+            .find(|head| head.is_file())
+    }
+
+    /// How many of folder name, product name and git remote `other` shares with this project,
+    /// used to find the project after it was moved.
+    pub fn moved_match_score(&self, other: &UnityProject) -> usize {
+        let folder_name = |project: &UnityProject| {
+            Path::new(&project.path)
+                .file_name()
+                .map(|name| name.to_os_string())
+        };
+        let same = |a: &str, b: &str| !a.is_empty() && a == b;
+        [
+            folder_name(self).is_some() && folder_name(self) == folder_name(other),
+            same(&self.product_name, &other.product_name),
+            same(&self.git_remote, &other.git_remote),
+        ]
+        .into_iter()
+        .filter(|matches| *matches)
+        .count()
+    }
+
+    /// True when the project folder, its version and git branch didn't change since `update_info`.
+    pub fn is_up_to_date(&self) -> bool {
+        self.fingerprint.is_current()
     }
 
     fn try_read_from_path(&self, path: &std::path::Path) -> Option<String> {
@@ -153,3 +239,32 @@ impl UnityProject {
         }
     }
 }
+
+/// Value of a top level `key: value` line in a serialized Unity asset.
+fn read_yaml_value(path: &Path, key: &str) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    content.lines().find_map(|line| {
+        let (name, value) = line.trim().split_once(':')?;
+        (name == key)
+            .then(|| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    })
+}
+
+/// `url` of the `origin` remote in a git config file.
+fn read_origin_url(path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let mut in_origin = false;
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_origin = line == "[remote \"origin\"]";
+        } else if in_origin {
+            if let Some((name, value)) = line.split_once('=') {
+                if name.trim() == "url" {
+                    return Some(value.trim().to_string());
+                }
+            }
+        }
+    }
+    None
+}
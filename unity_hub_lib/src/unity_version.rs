@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReleaseStream {
+    Experimental,
+    Alpha,
+    Beta,
+    Final,
+    /// Final release built for China, marked with `c`.
+    China,
+    Patch,
+}
+
+impl ReleaseStream {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'x' => Some(ReleaseStream::Experimental),
+            'a' => Some(ReleaseStream::Alpha),
+            'b' => Some(ReleaseStream::Beta),
+            'f' => Some(ReleaseStream::Final),
+            'c' => Some(ReleaseStream::China),
+            'p' => Some(ReleaseStream::Patch),
+            _ => None,
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            ReleaseStream::Experimental => 'x',
+            ReleaseStream::Alpha => 'a',
+            ReleaseStream::Beta => 'b',
+            ReleaseStream::Final => 'f',
+            ReleaseStream::China => 'c',
+            ReleaseStream::Patch => 'p',
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReleaseStream::Experimental => "experimental",
+            ReleaseStream::Alpha => "alpha",
+            ReleaseStream::Beta => "beta",
+            ReleaseStream::Final => "final",
+            ReleaseStream::China => "china",
+            ReleaseStream::Patch => "patch",
+        }
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        matches!(
+            self,
+            ReleaseStream::Experimental | ReleaseStream::Alpha | ReleaseStream::Beta
+        )
+    }
+}
+
+/// Parsed editor version like `2022.3.10f1`, ordered the way Unity releases them.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnityVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub stream: ReleaseStream,
+    pub build: u32,
+}
+
+impl UnityVersion {
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let mut parts = text.splitn(3, '.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let rest = parts.next()?;
+
+        let stream_pos = rest.find(|c: char| !c.is_ascii_digit())?;
+        let patch = rest[..stream_pos].parse().ok()?;
+        let mut chars = rest[stream_pos..].chars();
+        let stream = ReleaseStream::from_char(chars.next()?)?;
+        let build = chars.as_str().parse().ok()?;
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            stream,
+            build,
+        })
+    }
+
+    /// Long term support release, the last minor version of a yearly cycle.
+    pub fn is_lts(&self) -> bool {
+        if self.stream.is_prerelease() {
+            return false;
+        }
+        match self.major {
+            2017..=2019 => self.minor == 4,
+            2020..=2022 => self.minor == 3,
+            // Unity 6 releases, `6000.0` and `6000.3` are LTS, minor versions between are updates.
+            6000.. => matches!(self.minor, 0 | 3),
+            _ => false,
+        }
+    }
+
+    /// Short description of the support track, `LTS`, `Tech`, `Beta` and so on.
+    pub fn track(&self) -> &'static str {
+        match self.stream {
+            ReleaseStream::Experimental => "Experimental",
+            ReleaseStream::Alpha => "Alpha",
+            ReleaseStream::Beta => "Beta",
+            _ if self.is_lts() => "LTS",
+            _ if self.major >= 6000 => "Update",
+            _ => "Tech",
+        }
+    }
+}
+
+impl fmt::Display for UnityVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}{}{}",
+            self.major,
+            self.minor,
+            self.patch,
+            self.stream.as_char(),
+            self.build
+        )
+    }
+}
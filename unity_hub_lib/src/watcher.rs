@@ -0,0 +1,164 @@
+use crate::hub::Hub;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WatchEvent {
+    /// Something was added to or removed from a search path, e.g. an editor was installed.
+    SearchPathChanged(PathBuf),
+    /// A folder appeared in or disappeared from a project root, e.g. a project was cloned.
+    ProjectRootChanged(PathBuf),
+    /// `ProjectVersion.txt` or the git branch of the project changed, holds `UnityProject::path`.
+    ProjectChanged(String),
+}
+
+/// Directory being watched and what a change inside of it means.
+#[derive(Debug, Clone)]
+struct Target {
+    dir: PathBuf,
+    /// Only changes of this file are reported, any change when `None`.
+    file: Option<&'static str>,
+    event: WatchEvent,
+}
+
+/// Watches search paths, project roots and project files, reporting changes to `on_event` from
+/// a background thread. Call `sync` whenever editors, projects or settings change.
+pub struct HubWatcher {
+    watcher: RecommendedWatcher,
+    targets: Arc<Mutex<Vec<Target>>>,
+    watched: HashSet<PathBuf>,
+}
+
+impl HubWatcher {
+    pub fn new(on_event: impl Fn(WatchEvent) + Send + 'static) -> notify::Result<Self> {
+        let targets: Arc<Mutex<Vec<Target>>> = Arc::default();
+        let handler_targets = targets.clone();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else {
+                return;
+            };
+            if matches!(event.kind, EventKind::Access(_)) {
+                return;
+            }
+            let Ok(targets) = handler_targets.lock() else {
+                return;
+            };
+            let mut events = HashSet::new();
+            for path in &event.paths {
+                for target in targets.iter() {
+                    if target_matches(target, path) {
+                        events.insert(target.event.clone());
+                    }
+                }
+            }
+            drop(targets);
+            events.into_iter().for_each(&on_event);
+        })?;
+
+        Ok(Self {
+            watcher,
+            targets,
+            watched: HashSet::new(),
+        })
+    }
+
+    /// Watches enabled search paths, project roots and files of every known project.
+    pub fn sync(&mut self, hub: &Hub) {
+        let mut targets = Vec::new();
+        // Folders are created before their content is in place, so their children are watched
+        // too, e.g. `Editor` showing up in a new `<version>` folder.
+        let mut add_with_children = |dir: PathBuf, event: WatchEvent| {
+            for child in child_dirs(&dir) {
+                targets.push(Target {
+                    dir: child,
+                    file: None,
+                    event: event.clone(),
+                });
+            }
+            targets.push(Target {
+                dir,
+                file: None,
+                event,
+            });
+        };
+        for search_path in &hub.config.unity_search_paths {
+            if search_path.enabled {
+                let dir = search_path.resolved();
+                add_with_children(dir.clone(), WatchEvent::SearchPathChanged(dir));
+            }
+        }
+        for root in &hub.config.project_roots {
+            if root.enabled {
+                let dir = root.resolved();
+                add_with_children(dir.clone(), WatchEvent::ProjectRootChanged(dir));
+            }
+        }
+        for project in &hub.projects {
+            let event = WatchEvent::ProjectChanged(project.path.clone());
+            targets.push(Target {
+                dir: Path::new(&project.path).join("ProjectSettings"),
+                file: Some("ProjectVersion.txt"),
+                event: event.clone(),
+            });
+            // Git replaces `HEAD` through a rename, so the folder is watched instead of the file.
+            if let Some(git_dir) = project
+                .git_head_path()
+                .and_then(|head| head.parent().map(Path::to_path_buf))
+            {
+                targets.push(Target {
+                    dir: git_dir,
+                    file: Some("HEAD"),
+                    event,
+                });
+            }
+        }
+
+        let dirs: HashSet<PathBuf> = targets
+            .iter()
+            .map(|target| target.dir.clone())
+            .filter(|dir| dir.is_dir())
+            .collect();
+        for removed in self.watched.difference(&dirs) {
+            let _ = self.watcher.unwatch(removed);
+        }
+        let mut watched = HashSet::new();
+        for dir in dirs {
+            if self.watched.contains(&dir)
+                || self
+                    .watcher
+                    .watch(&dir, RecursiveMode::NonRecursive)
+                    .is_ok()
+            {
+                watched.insert(dir);
+            }
+        }
+        self.watched = watched;
+        if let Ok(mut current) = self.targets.lock() {
+            *current = targets;
+        }
+    }
+}
+
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn target_matches(target: &Target, path: &Path) -> bool {
+    match target.file {
+        Some(file) => {
+            path.parent() == Some(target.dir.as_path())
+                && path.file_name().is_some_and(|name| name == file)
+        }
+        None => path.parent() == Some(target.dir.as_path()) || path == target.dir,
+    }
+}